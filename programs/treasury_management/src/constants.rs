@@ -2,3 +2,52 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+/// Treasury names double as PDA seeds, which are capped at 32 bytes.
+#[constant]
+pub const MAX_TREASURY_NAME_LENGTH: usize = 32;
+
+#[constant]
+pub const MAX_SIGNERS: usize = 10;
+
+/// Three days, applied to new treasuries until the config is updated.
+#[constant]
+pub const DEFAULT_VOTING_PERIOD: i64 = 259_200;
+
+/// Seven days after voting closes for an approved proposal to be executed.
+#[constant]
+pub const DEFAULT_EXECUTION_GRACE_PERIOD: i64 = 604_800;
+
+/// One day between a proposal's approval and its earliest execution.
+#[constant]
+pub const DEFAULT_EXECUTION_DELAY: i64 = 86_400;
+
+/// Upper bound on the execution delay, including any amount scaling.
+#[constant]
+pub const MAX_EXECUTION_DELAY: i64 = 2_592_000;
+
+/// Share of the governance mint's supply that must vote on a
+/// token-weighted proposal for the result to count.
+#[constant]
+pub const DEFAULT_QUORUM_PERCENTAGE: u8 = 10;
+
+/// Share of the cast token weight that must be in favour to approve.
+#[constant]
+pub const DEFAULT_APPROVAL_PERCENTAGE: u8 = 50;
+
+#[constant]
+pub const MAX_PROPOSAL_TITLE_LENGTH: usize = 64;
+
+#[constant]
+pub const MAX_PROPOSAL_DESCRIPTION_LENGTH: usize = 512;
+
+#[constant]
+pub const MAX_PROPOSAL_INSTRUCTIONS: usize = 8;
+
+/// Most missed intervals a single `execute_recurring_payment` call may pay.
+#[constant]
+pub const MAX_RECURRING_CATCH_UP: u8 = 12;
+
+/// Longest milestone description that fits the milestone account.
+#[constant]
+pub const MAX_MILESTONE_DESCRIPTION_LENGTH: usize = 200;
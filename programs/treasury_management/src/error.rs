@@ -11,7 +11,7 @@ pub enum TreasuryManagementError {
 	InsufficientFunds,
 	#[msg("User does not have sufficient permissions for this action")]
 	InsufficientPermissions,
-	#[msg("Signature threshold must be greater than 0 and less than or equal to the number of signers")]
+	#[msg("Signature threshold must be greater than 0 and less than or equal to the total signer weight")]
 	InvalidSignatureThreshold,
 	#[msg("Treasury operations are currently paused")]
 	TreasuryPaused,
@@ -35,4 +35,98 @@ pub enum TreasuryManagementError {
 	SpendingLimitExceeded,
 	#[msg("Stake target percentage must be between 0 and 100")]
 	InvalidStakePercentage,
+	#[msg("Treasury name must be between 1 and 32 bytes")]
+	InvalidTreasuryName,
+	#[msg("Signers must be non-empty, unique and at most 10")]
+	InvalidSigners,
+	#[msg("Arithmetic overflow")]
+	MathOverflow,
+	#[msg("Token mint does not match the one recorded for this payment")]
+	InvalidTokenMint,
+	#[msg("Token account does not match the expected mint or owner")]
+	InvalidTokenAccount,
+	#[msg("Recipients and amounts must be non-empty and of equal length")]
+	BatchLengthMismatch,
+	#[msg("Role type must be admin (1), treasurer (2) or contributor (3)")]
+	InvalidRoleType,
+	#[msg("Proposal title must be 1 to 64 bytes and description at most 512 bytes")]
+	InvalidProposalText,
+	#[msg("Only treasury signers can vote on proposals")]
+	NotTreasurySigner,
+	#[msg("This proposal is no longer open for voting")]
+	ProposalNotPending,
+	#[msg("Voting and execution grace periods must be positive")]
+	InvalidGovernancePeriod,
+	#[msg("The voting period for this proposal has ended")]
+	ProposalVotingClosed,
+	#[msg("This proposal has expired")]
+	ProposalExpired,
+	#[msg("This proposal has not expired yet")]
+	ProposalNotExpired,
+	#[msg("Execution delay must be between 0 and 30 days")]
+	InvalidExecutionDelay,
+	#[msg("This proposal is still within its execution timelock")]
+	ProposalTimelocked,
+	#[msg("Instruction proposals need 1 to 8 instructions and may not call this program")]
+	InvalidProposalInstructions,
+	#[msg("This instruction does not apply to this kind of proposal")]
+	ProposalKindMismatch,
+	#[msg("An account required by the proposal's instructions was not provided")]
+	MissingProposalAccount,
+	#[msg("Treasuries with more than one signer can only change config through a proposal")]
+	ConfigChangeRequiresProposal,
+	#[msg("This signer has not voted on this proposal")]
+	VoteNotFound,
+	#[msg("Every signer needs exactly one non-zero weight")]
+	InvalidSignerWeights,
+	#[msg("Quorum must be at most 100% and approval between 1% and 100%")]
+	InvalidGovernancePercentage,
+	#[msg("This treasury has no governance mint, or it differs from the one provided")]
+	GovernanceNotEnabled,
+	#[msg("This vote does not match the proposal's governance mode")]
+	GovernanceModeMismatch,
+	#[msg("Escrowed governance tokens are locked until voting closes on every proposal they voted on")]
+	GovernanceTokensLocked,
+	#[msg("The governance mint cannot change while tokens are escrowed")]
+	GovernanceTokensEscrowed,
+	#[msg("No governance tokens are escrowed to vote with")]
+	NoVotingWeight,
+	#[msg("Voting on this proposal is still open")]
+	ProposalVotingOpen,
+	#[msg("Votes can only be delegated to someone else, with an expiry in the future")]
+	InvalidDelegation,
+	#[msg("This delegation does not belong to the voter or has expired")]
+	DelegationNotActive,
+	#[msg("Streams need a positive period, start before end and a cliff between the two")]
+	InvalidStreamSchedule,
+	#[msg("Stream id must be the treasury's next stream id")]
+	InvalidStreamId,
+	#[msg("Payment stream is already paused")]
+	PaymentStreamPaused,
+	#[msg("Payment stream is not paused")]
+	PaymentStreamNotPaused,
+	#[msg("The new stream recipient must be a different, non-default wallet")]
+	InvalidStreamRecipient,
+	#[msg("Transferring this stream needs a co-signature from someone who can manage its streams")]
+	StreamTransferNotApproved,
+	#[msg("Recurring payments need a positive interval or a day from 1 to 31 (and a month from 1 to 12 for quarterly and yearly schedules), a non-zero payment cap and an end date after the first payment")]
+	InvalidRecurringSchedule,
+	#[msg("Recurring payment is not active")]
+	RecurringPaymentInactive,
+	#[msg("No installment is due for this recurring payment yet")]
+	RecurringPaymentNotDue,
+	#[msg("A recurring payment can catch up on 1 to 12 intervals at a time")]
+	InvalidRecurringCatchUp,
+	#[msg("Recurring payment is paused")]
+	RecurringPaymentPaused,
+	#[msg("Recurring payment is not paused")]
+	RecurringPaymentNotPaused,
+	#[msg("Token account is not the destination of this recurring payment")]
+	InvalidRecurringDestination,
+	#[msg("Milestone descriptions can be at most 200 bytes")]
+	InvalidMilestoneDescription,
+	#[msg("Proposal no longer has enough signer weight in favour under the current signers")]
+	ProposalApprovalLapsed,
+	#[msg("A recurring payment's destination cannot be set and cleared at once")]
+	ConflictingRecurringDestination,
 }
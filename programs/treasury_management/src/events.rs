@@ -0,0 +1,52 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecurringPaymentAction {
+	Paused,
+	Resumed,
+	Skipped,
+	Updated,
+	Cancelled,
+}
+
+/// Emitted whenever a recurring payment's schedule or terms are changed
+/// outside of paying an installment. Unchanged fields carry the same value
+/// in both `old_` and `new_`; a cancelled payment keeps its last terms.
+#[event]
+#[derive(Debug, PartialEq, Eq)]
+pub struct RecurringPaymentChanged {
+	pub recurring_payment: Pubkey,
+	pub treasury: Pubkey,
+	pub actor: Pubkey,
+	pub action: RecurringPaymentAction,
+	pub old_amount: u64,
+	pub new_amount: u64,
+	pub old_destination: Option<Pubkey>,
+	pub new_destination: Option<Pubkey>,
+	pub old_next_payment_date: i64,
+	pub new_next_payment_date: i64,
+}
+
+impl RecurringPaymentChanged {
+	pub fn new(
+		action: RecurringPaymentAction,
+		actor: Pubkey,
+		recurring_payment: Pubkey,
+		before: &RecurringPayment,
+		after: &RecurringPayment,
+	) -> Self {
+		Self {
+			recurring_payment,
+			treasury: after.treasury,
+			actor,
+			action,
+			old_amount: before.amount,
+			new_amount: after.amount,
+			old_destination: before.destination,
+			new_destination: after.destination,
+			old_next_payment_date: before.next_payment_date,
+			new_next_payment_date: after.next_payment_date,
+		}
+	}
+}
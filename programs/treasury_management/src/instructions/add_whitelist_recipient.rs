@@ -29,6 +29,16 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			init,
 			space=166,
@@ -48,8 +58,9 @@ use anchor_spl::{
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` whitelist_entry: [WhitelistedRecipient] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` whitelist_entry: [WhitelistedRecipient] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
@@ -61,6 +72,12 @@ pub fn handler(
 	recipient: Pubkey,
 	label: String,
 ) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ManageRoles,
+	)?;
+
     // Implement your business logic here...
 	
 	Ok(())
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -23,6 +24,7 @@ use anchor_spl::{
 		can_vote: bool,
 	)]
 	pub struct AssignRole<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
 		#[account(
@@ -33,11 +35,39 @@ use anchor_spl::{
 			bump,
 		)]
 		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			init_if_needed,
+			space=95,
+			payer=authority,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				user.as_ref(),
+			],
+			bump,
+		)]
+		pub role: Account<'info, Role>,
+
+		pub system_program: Program<'info, System>,
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` role: [Role] Created on first assignment, updated afterwards.
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
@@ -61,7 +91,34 @@ pub fn handler(
 	can_propose: bool,
 	can_vote: bool,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ManageRoles,
+	)?;
+
+	require!(
+		Role::is_valid_role_type(role_type),
+		TreasuryManagementError::InvalidRoleType
+	);
+
+	let role = &mut ctx.accounts.role;
+
+	// A fresh account starts its spending period now; reassignments keep their usage.
+	if role.treasury == Pubkey::default() {
+		role.treasury = ctx.accounts.treasury.key();
+		role.user = user;
+		role.spending_limit_used = 0;
+		role.last_limit_reset = Clock::get()?.unix_timestamp;
+	}
+
+	role.role_type = role_type;
+	role.can_execute_payments = can_execute_payments;
+	role.can_create_streams = can_create_streams;
+	role.can_manage_roles = can_manage_roles;
+	role.can_view_treasury = can_view_treasury;
+	role.can_propose = can_propose;
+	role.can_vote = can_vote;
+
 	Ok(())
 }
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -29,32 +30,39 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
-		pub token_mint: Account<'info, Mint>,
-
 		#[account(
 			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub source: UncheckedAccount<'info>,
+		pub authority_role: Option<Account<'info, Role>>,
+
+		pub token_mint: Account<'info, Mint>,
 
 		#[account(
 			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub destination: UncheckedAccount<'info>,
+		pub token_vault: Account<'info, TokenAccount>,
 
 		pub csl_spl_token_v0_0_0: Program<'info, Token>,
 	}
 
 	impl<'info> BatchTransfer<'info> {
-		pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+		pub fn cpi_csl_spl_token_transfer(&self, destination: AccountInfo<'info>, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
 			anchor_spl::token::transfer(
-				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
 					anchor_spl::token::Transfer {
-						from: self.source.to_account_info(),
-						to: self.destination.to_account_info(),
-						authority: self.authority.to_account_info()
-					}
+						from: self.token_vault.to_account_info(),
+						to: destination,
+						authority: self.treasury.to_account_info()
+					},
+					signer_seeds,
 				),
 				amount, 
 			)
@@ -65,27 +73,80 @@ use anchor_spl::{
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[]` token_mint: [Mint] 
-/// 3. `[writable]` source: [AccountInfo] The source account.
-/// 4. `[writable]` destination: [AccountInfo] The destination account.
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[]` token_mint: [Mint] 
+/// 4. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
 /// 5. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///    6.. `[writable]` remaining accounts: [TokenAccount] One `token_mint` account per recipient, in order.
 ///
 /// Data:
 /// - name: [String] 
 /// - recipients: [Vec<Pubkey>] 
 /// - amounts: [Vec<u64>] 
-pub fn handler(
-	ctx: Context<BatchTransfer>,
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, BatchTransfer<'info>>,
 	name: String,
 	recipients: Vec<Pubkey>,
 	amounts: Vec<u64>,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	// Cpi calls wrappers
-	ctx.accounts.cpi_csl_spl_token_transfer(
-		Default::default(),
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ExecutePayments,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	require!(
+		!recipients.is_empty()
+			&& recipients.len() == amounts.len()
+			&& recipients.len() == ctx.remaining_accounts.len(),
+		TreasuryManagementError::BatchLengthMismatch
+	);
+
+	let mut total: u64 = 0;
+	for amount in amounts.iter() {
+		require!(*amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+		total = total
+			.checked_add(*amount)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+	}
+	require!(
+		ctx.accounts.token_vault.amount >= total,
+		TreasuryManagementError::InsufficientFunds
+	);
+
+	let accounts = &mut *ctx.accounts;
+	accounts.treasury.record_spending(
+		&accounts.authority.key(),
+		accounts.authority_role.as_deref_mut(),
+		total,
 	)?;
 
+	let treasury_bump = [ctx.bumps.treasury];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"treasury",
+		name.as_bytes(),
+		&treasury_bump,
+	]];
+
+	let token_mint = ctx.accounts.token_mint.key();
+	for ((recipient, amount), destination_info) in recipients.iter()
+		.zip(amounts.iter())
+		.zip(ctx.remaining_accounts.iter())
+	{
+		require!(destination_info.is_writable, TreasuryManagementError::InvalidTokenAccount);
+		let destination = Account::<TokenAccount>::try_from(destination_info)?;
+		require!(
+			destination.mint == token_mint && destination.owner == *recipient,
+			TreasuryManagementError::InvalidTokenAccount
+		);
+
+		ctx.accounts.cpi_csl_spl_token_transfer(
+			destination_info.clone(),
+			*amount,
+			signer_seeds,
+		)?;
+	}
+
 	Ok(())
 }
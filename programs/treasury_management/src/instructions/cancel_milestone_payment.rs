@@ -0,0 +1,202 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct CancelMilestonePayment<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			close=creator,
+			seeds = [
+				b"milestone",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub milestone_payment: Account<'info, MilestonePayment>,
+
+		#[account(
+			mut,
+			address = milestone_payment.created_by,
+		)]
+		pub creator: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				creator.key().as_ref(),
+			],
+			bump,
+		)]
+		pub creator_role: Option<Account<'info, Role>>,
+
+		#[account(
+			address = milestone_payment.token_mint @ TreasuryManagementError::InvalidTokenMint,
+		)]
+		pub token_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
+		)]
+		pub token_vault: Account<'info, TokenAccount>,
+
+		/// CHECK: holds no data; only signs for the treasury's escrow vaults
+		#[account(
+			seeds = [
+				b"escrow_authority",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub escrow_authority: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"milestone_escrow",
+				milestone_payment.key().as_ref(),
+			],
+			bump,
+			token::mint = token_mint,
+			token::authority = escrow_authority,
+		)]
+		pub escrow_vault: Account<'info, TokenAccount>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> CancelMilestonePayment<'info> {
+		pub fn cpi_csl_spl_token_transfer_to_vault(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.escrow_vault.to_account_info(),
+						to: self.token_vault.to_account_info(),
+						authority: self.escrow_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.escrow_vault.to_account_info(),
+						destination: self.creator.to_account_info(),
+						authority: self.escrow_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` milestone_payment: [MilestonePayment] 
+/// 4. `[writable]` creator: [AccountInfo] Whoever created the milestone; receives the rent of both closed accounts.
+/// 5. `[writable]` creator_role: [Role] Optional, the creator's role; the milestone's amount is credited back to its spending limit within the period it was charged to.
+/// 6. `[]` token_mint: [Mint] 
+/// 7. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 8. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 9. `[writable]` escrow_vault: [TokenAccount] The milestone's escrow, emptied into `token_vault` and closed.
+/// 10. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler(
+	ctx: Context<CancelMilestonePayment>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+	require!(
+		!ctx.accounts.milestone_payment.is_completed,
+		TreasuryManagementError::MilestoneAlreadyCompleted
+	);
+
+	let treasury_key = ctx.accounts.treasury.key();
+	let escrow_authority_bump = [ctx.bumps.escrow_authority];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"escrow_authority",
+		treasury_key.as_ref(),
+		&escrow_authority_bump,
+	]];
+
+	let amount = ctx.accounts.escrow_vault.amount;
+	if amount > 0 {
+		ctx.accounts.cpi_csl_spl_token_transfer_to_vault(amount, signer_seeds)?;
+	}
+
+	ctx.accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
+
+	// The creator was charged the full amount when the milestone was created,
+	// which only counts against their limit until that period is over.
+	let accounts = &mut *ctx.accounts;
+	accounts.treasury.release_spending(
+		&accounts.creator.key(),
+		accounts.creator_role.as_deref_mut(),
+		accounts.milestone_payment.amount,
+		accounts.milestone_payment.charge_period,
+	);
+
+	msg!(
+		"Milestone {} on treasury {} cancelled by {}, returning {} to the vault",
+		id,
+		name,
+		ctx.accounts.authority.key(),
+		amount,
+	);
+
+	// The milestone account is closed by its `close` constraint once the handler returns.
+	Ok(())
+}
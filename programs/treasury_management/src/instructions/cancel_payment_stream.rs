@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -13,7 +14,7 @@ use anchor_spl::{
 	#[derive(Accounts)]
 	#[instruction(
 		name: String,
-		recipient: Pubkey,
+		stream_id: u64,
 	)]
 	pub struct CancelPaymentStream<'info> {
 		pub authority: Signer<'info>,
@@ -27,32 +28,140 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			mut,
+			close=creator,
 			seeds = [
 				b"payment_stream",
 				treasury.key().as_ref(),
-				recipient.as_ref(),
+				stream_id.to_le_bytes().as_ref(),
 			],
 			bump,
 		)]
 		pub payment_stream: Account<'info, PaymentStream>,
+
+		#[account(
+			mut,
+			address = payment_stream.created_by,
+		)]
+		pub creator: SystemAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				creator.key().as_ref(),
+			],
+			bump,
+		)]
+		pub creator_role: Option<Account<'info, Role>>,
+
+		#[account(
+			address = payment_stream.token_mint @ TreasuryManagementError::InvalidTokenMint,
+		)]
+		pub token_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
+		)]
+		pub token_vault: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			token::mint = token_mint,
+			token::authority = payment_stream.recipient,
+		)]
+		pub destination: Account<'info, TokenAccount>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> CancelPaymentStream<'info> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.token_vault.to_account_info(),
+						to: self.destination.to_account_info(),
+						authority: self.treasury.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
 	}
 
+
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` payment_stream: [PaymentStream] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] Closed, with its rent refunded to `creator`.
+/// 4. `[writable]` creator: [AccountInfo] The stream's `created_by`.
+/// 5. `[writable]` creator_role: [Role] Optional, the creator's role; the unspent budget is credited back to its spending limit within the period it was charged to.
+/// 6. `[]` token_mint: [Mint] 
+/// 7. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 8. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
-/// - recipient: [Pubkey] 
+/// - stream_id: [u64] 
 pub fn handler(
 	ctx: Context<CancelPaymentStream>,
 	name: String,
-	recipient: Pubkey,
+	stream_id: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	// Pay out what the recipient has already earned. The rest of the budget
+	// was never escrowed, so it simply stays in the vault, and the creator
+	// gets it back on their spending limit if it was charged this period.
+	let amount = ctx.accounts.payment_stream.cancel(Clock::get()?.unix_timestamp)?;
+	let accounts = &mut *ctx.accounts;
+	accounts.treasury.release_spending(
+		&accounts.creator.key(),
+		accounts.creator_role.as_deref_mut(),
+		accounts.payment_stream.unspent_budget(),
+		accounts.payment_stream.charge_period,
+	);
+	if amount > 0 {
+		require!(
+			ctx.accounts.token_vault.amount >= amount,
+			TreasuryManagementError::InsufficientFunds
+		);
+
+		let treasury_bump = [ctx.bumps.treasury];
+		let signer_seeds: &[&[&[u8]]] = &[&[
+			b"treasury",
+			name.as_bytes(),
+			&treasury_bump,
+		]];
+
+		ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)?;
+	}
+
+	msg!("Payment stream {} on treasury {} cancelled", stream_id, name);
+
+	// The stream account is closed by its `close` constraint once the handler returns.
 	Ok(())
 }
@@ -0,0 +1,89 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct CancelProposal<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			close=proposer,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		#[account(
+			mut,
+			address = proposal.proposer,
+		)]
+		pub proposer: SystemAccount<'info>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The proposer, or one of the cancelling signers.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] Closed, with its rent refunded to `proposer`.
+/// 3. `[writable]` proposer: [AccountInfo] The proposal's original proposer.
+///    4..n. `[signer]` Remaining accounts: further treasury signers co-signing the cancellation.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, CancelProposal<'info>>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	let treasury = &ctx.accounts.treasury;
+	let authority = ctx.accounts.authority.key();
+	let proposal = &mut ctx.accounts.proposal;
+
+	require!(
+		proposal.status != ProposalStatus::Executed,
+		TreasuryManagementError::ProposalAlreadyExecuted
+	);
+
+	// The proposer may withdraw their own proposal until it is decided;
+	// afterwards it takes a threshold of signer weight on this transaction.
+	let by_proposer = authority == proposal.proposer
+		&& proposal.status == ProposalStatus::Pending;
+	if !by_proposer {
+		let co_signers = ctx.remaining_accounts
+			.iter()
+			.filter(|info| info.is_signer)
+			.map(|info| info.key());
+		let approvals = treasury.weight_of_signers(std::iter::once(authority).chain(co_signers));
+		require!(
+			approvals >= treasury.threshold as u32,
+			TreasuryManagementError::InsufficientPermissions
+		);
+	}
+
+	proposal.status = ProposalStatus::Cancelled;
+	msg!("Proposal {} on treasury {} cancelled", id, name);
+
+	// The proposal account is closed by its `close` constraint once the handler returns.
+	Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		recipient: Pubkey,
+	)]
+	pub struct CancelRecurringPayment<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			close=creator,
+			seeds = [
+				b"recurring",
+				treasury.key().as_ref(),
+				recipient.as_ref(),
+			],
+			bump,
+		)]
+		pub recurring_payment: Account<'info, RecurringPayment>,
+
+		#[account(
+			mut,
+			address = recurring_payment.created_by,
+		)]
+		pub creator: SystemAccount<'info>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[writable]` creator: [AccountInfo] Whoever created the recurring payment; receives the account's rent.
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+pub fn handler(
+	ctx: Context<CancelRecurringPayment>,
+	name: String,
+	recipient: Pubkey,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+
+	// Installments that are due but unpaid are dropped along with the rest.
+	emit!(RecurringPaymentChanged::new(
+		RecurringPaymentAction::Cancelled,
+		ctx.accounts.authority.key(),
+		ctx.accounts.recurring_payment.key(),
+		&ctx.accounts.recurring_payment,
+		&ctx.accounts.recurring_payment,
+	));
+
+	// The account is closed by its `close` constraint once the handler returns.
+	Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+		vote_for: bool,
+	)]
+	pub struct CastTokenVote<'info> {
+		#[account(mut)]
+		pub voter: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"voter_escrow",
+				treasury.key().as_ref(),
+				voter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub voter_escrow: Account<'info, VoterEscrow>,
+
+		#[account(
+			init_if_needed,
+			space=89,
+			payer=voter,
+			seeds = [
+				b"vote_record",
+				proposal.key().as_ref(),
+				voter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vote_record: Account<'info, VoteRecord>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` voter: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] A token-weighted proposal.
+/// 3. `[writable]` voter_escrow: [VoterEscrow] The voter's escrowed governance tokens, used as vote weight.
+/// 4. `[writable]` vote_record: [VoteRecord] The voter's vote on this proposal, created on first vote.
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+/// - vote_for: [bool] 
+pub fn handler(
+	ctx: Context<CastTokenVote>,
+	name: String,
+	id: u64,
+	vote_for: bool,
+) -> Result<()> {
+	let proposal = &mut ctx.accounts.proposal;
+	let voter_escrow = &mut ctx.accounts.voter_escrow;
+	let vote_record = &mut ctx.accounts.vote_record;
+
+	vote_record.proposal = proposal.key();
+	vote_record.voter = ctx.accounts.voter.key();
+	proposal.record_token_vote(
+		vote_record,
+		vote_for,
+		voter_escrow.amount,
+		&ctx.accounts.treasury,
+		Clock::get()?.unix_timestamp,
+	)?;
+
+	// Keep the weight in escrow until this proposal's voting closes.
+	voter_escrow.lock_until(proposal.voting_deadline);
+
+	Ok(())
+}
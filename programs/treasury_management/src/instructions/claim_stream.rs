@@ -0,0 +1,117 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		stream_id: u64,
+	)]
+	pub struct ClaimStream<'info> {
+		pub recipient: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payment_stream",
+				treasury.key().as_ref(),
+				stream_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = recipient @ TreasuryManagementError::InsufficientPermissions,
+		)]
+		pub payment_stream: Account<'info, PaymentStream>,
+
+		#[account(
+			address = payment_stream.token_mint @ TreasuryManagementError::InvalidTokenMint,
+		)]
+		pub token_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
+		)]
+		pub token_vault: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			token::mint = token_mint,
+			token::authority = recipient,
+		)]
+		pub destination: Account<'info, TokenAccount>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> ClaimStream<'info> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.token_vault.to_account_info(),
+						to: self.destination.to_account_info(),
+						authority: self.treasury.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+	}
+
+
+/// Accounts:
+/// 0. `[signer]` recipient: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` payment_stream: [PaymentStream] 
+/// 3. `[]` token_mint: [Mint] 
+/// 4. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 5. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+pub fn handler(
+	ctx: Context<ClaimStream>,
+	name: String,
+	stream_id: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_not_paused()?;
+
+	let amount = ctx.accounts.payment_stream.record_payout(Clock::get()?.unix_timestamp)?;
+	require!(
+		ctx.accounts.token_vault.amount >= amount,
+		TreasuryManagementError::InsufficientFunds
+	);
+
+	let treasury_bump = [ctx.bumps.treasury];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"treasury",
+		name.as_bytes(),
+		&treasury_bump,
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)?;
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -28,6 +29,16 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			mut,
 			seeds = [
@@ -39,47 +50,111 @@ use anchor_spl::{
 		)]
 		pub milestone_payment: Account<'info, MilestonePayment>,
 
+		#[account(
+			mut,
+			address = milestone_payment.created_by,
+		)]
+		pub creator: SystemAccount<'info>,
+
+		#[account(
+			address = milestone_payment.token_mint @ TreasuryManagementError::InvalidTokenMint,
+		)]
 		pub token_mint: Account<'info, Mint>,
 
 		#[account(
 			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub source: UncheckedAccount<'info>,
+		pub token_vault: Account<'info, TokenAccount>,
+
+		/// CHECK: holds no data; only signs for the treasury's escrow vaults
+		#[account(
+			seeds = [
+				b"escrow_authority",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub escrow_authority: UncheckedAccount<'info>,
 
 		#[account(
 			mut,
+			seeds = [
+				b"milestone_escrow",
+				milestone_payment.key().as_ref(),
+			],
+			bump,
+			token::mint = token_mint,
+			token::authority = escrow_authority,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub destination: UncheckedAccount<'info>,
+		pub escrow_vault: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			token::mint = token_mint,
+			token::authority = milestone_payment.recipient,
+		)]
+		pub destination: Account<'info, TokenAccount>,
 
 		pub csl_spl_token_v0_0_0: Program<'info, Token>,
 	}
 
 	impl<'info> CompleteMilestone<'info> {
-		pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
 			anchor_spl::token::transfer(
-				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
 					anchor_spl::token::Transfer {
-						from: self.source.to_account_info(),
+						from: self.escrow_vault.to_account_info(),
 						to: self.destination.to_account_info(),
-						authority: self.authority.to_account_info()
-					}
+						authority: self.escrow_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+
+		pub fn cpi_csl_spl_token_transfer_to_vault(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.escrow_vault.to_account_info(),
+						to: self.token_vault.to_account_info(),
+						authority: self.escrow_authority.to_account_info()
+					},
+					signer_seeds,
 				),
 				amount, 
 			)
 		}
-	}
 
+		pub fn cpi_csl_spl_token_close_account(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::close_account(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::CloseAccount {
+						account: self.escrow_vault.to_account_info(),
+						destination: self.creator.to_account_info(),
+						authority: self.escrow_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+			)
+		}
+	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` milestone_payment: [MilestonePayment] 
-/// 3. `[]` token_mint: [Mint] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` milestone_payment: [MilestonePayment] 
+/// 4. `[writable]` creator: [AccountInfo] Whoever created the milestone; receives the escrow account's rent.
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 7. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 8. `[writable]` escrow_vault: [TokenAccount] The milestone's escrow, closed once released.
+/// 9. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 10. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
@@ -89,12 +164,33 @@ pub fn handler(
 	name: String,
 	id: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	// Cpi calls wrappers
-	ctx.accounts.cpi_csl_spl_token_transfer(
-		Default::default(),
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ExecutePayments,
 	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	let amount = ctx.accounts.milestone_payment.complete(Clock::get()?.unix_timestamp)?;
+
+	let treasury_key = ctx.accounts.treasury.key();
+	let escrow_authority_bump = [ctx.bumps.escrow_authority];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"escrow_authority",
+		treasury_key.as_ref(),
+		&escrow_authority_bump,
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)?;
+
+	// Anything sent to the escrow beyond the milestone amount goes back to
+	// the treasury so the escrow can be closed.
+	let surplus = ctx.accounts.escrow_vault.amount.saturating_sub(amount);
+	if surplus > 0 {
+		ctx.accounts.cpi_csl_spl_token_transfer_to_vault(surplus, signer_seeds)?;
+	}
+
+	ctx.accounts.cpi_csl_spl_token_close_account(signer_seeds)?;
 
 	Ok(())
 }
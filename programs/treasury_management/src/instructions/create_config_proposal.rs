@@ -0,0 +1,102 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+		title: String,
+		description: String,
+		config: TreasuryConfig,
+	)]
+	pub struct CreateConfigProposal<'info> {
+		#[account(mut)]
+		pub proposer: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				proposer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub proposer_role: Option<Account<'info, Role>>,
+
+		#[account(
+			init,
+			space=1504 + TreasuryConfig::SPACE,
+			payer=proposer,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` proposer: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` proposer_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+/// - title: [String] 
+/// - description: [String] 
+/// - config: [TreasuryConfig] The complete settings to apply once approved.
+pub fn handler(
+	ctx: Context<CreateConfigProposal>,
+	name: String,
+	id: u64,
+	title: String,
+	description: String,
+	config: TreasuryConfig,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.proposer.key(),
+		ctx.accounts.proposer_role.as_deref(),
+		Permission::Propose,
+	)?;
+
+	Proposal::validate_text(&title, &description)?;
+	config.validate()?;
+
+	let proposal = &mut ctx.accounts.proposal;
+
+	proposal.open(
+		&mut ctx.accounts.treasury,
+		ctx.accounts.proposer.key(),
+		title,
+		description,
+		Clock::get()?.unix_timestamp,
+	)?;
+	proposal.kind = ProposalKind::ConfigChange;
+	proposal.amount = 0;
+	proposal.token_mint = Pubkey::default();
+	proposal.recipient = Pubkey::default();
+	proposal.instructions = Vec::new();
+	proposal.config = Some(config);
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -15,7 +16,6 @@ use anchor_spl::{
 		name: String,
 		id: u64,
 		recipient: Pubkey,
-		token_mint: Pubkey,
 		amount: u64,
 		description: String,
 		category: u8,
@@ -33,9 +33,20 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			init,
-			space=366,
+			space=374,
 			payer=authority,
 			seeds = [
 				b"milestone",
@@ -46,20 +57,77 @@ use anchor_spl::{
 		)]
 		pub milestone_payment: Account<'info, MilestonePayment>,
 
+		pub token_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
+		)]
+		pub token_vault: Account<'info, TokenAccount>,
+
+		/// CHECK: holds no data; only signs for the treasury's escrow vaults
+		#[account(
+			seeds = [
+				b"escrow_authority",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub escrow_authority: UncheckedAccount<'info>,
+
+		#[account(
+			init,
+			payer=authority,
+			seeds = [
+				b"milestone_escrow",
+				milestone_payment.key().as_ref(),
+			],
+			bump,
+			token::mint = token_mint,
+			token::authority = escrow_authority,
+			token::token_program = csl_spl_token_v0_0_0,
+		)]
+		pub escrow_vault: Account<'info, TokenAccount>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
 		pub system_program: Program<'info, System>,
 	}
 
+	impl<'info> CreateMilestonePayment<'info> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.token_vault.to_account_info(),
+						to: self.escrow_vault.to_account_info(),
+						authority: self.treasury.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+	}
+
+
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
+/// 0. `[writable, signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` milestone_payment: [MilestonePayment] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` milestone_payment: [MilestonePayment] 
+/// 4. `[]` token_mint: [Mint] The mint the milestone pays in.
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 7. `[writable]` escrow_vault: [TokenAccount] Holds the milestone's funds until it is completed or cancelled.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
 /// - id: [u64] 
 /// - recipient: [Pubkey] 
-/// - token_mint: [Pubkey] 
 /// - amount: [u64] 
 /// - description: [String] type
 /// - category: [u8] 
@@ -68,12 +136,52 @@ pub fn handler(
 	name: String,
 	id: u64,
 	recipient: Pubkey,
-	token_mint: Pubkey,
 	amount: u64,
 	description: String,
 	category: u8,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	MilestonePayment::validate(amount, &description)?;
+	require!(
+		ctx.accounts.token_vault.amount >= amount,
+		TreasuryManagementError::InsufficientFunds
+	);
+
+	let accounts = &mut *ctx.accounts;
+	let authority = accounts.authority.key();
+	let charge_period = accounts.treasury.record_spending(
+		&authority,
+		accounts.authority_role.as_deref_mut(),
+		amount,
+	)?;
+
+	let milestone = &mut accounts.milestone_payment;
+	milestone.treasury = accounts.treasury.key();
+	milestone.recipient = recipient;
+	milestone.token_mint = accounts.token_mint.key();
+	milestone.amount = amount;
+	milestone.description = description;
+	milestone.is_completed = false;
+	milestone.created_at = Clock::get()?.unix_timestamp;
+	milestone.completed_at = 0;
+	milestone.created_by = authority;
+	milestone.category = category;
+	milestone.charge_period = charge_period;
+
+	let treasury_bump = [ctx.bumps.treasury];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"treasury",
+		name.as_bytes(),
+		&treasury_bump,
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)?;
+
 	Ok(())
 }
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -13,35 +14,51 @@ use anchor_spl::{
 	#[derive(Accounts)]
 	#[instruction(
 		name: String,
+		stream_id: u64,
 		recipient: Pubkey,
 		token_mint: Pubkey,
 		amount_per_period: u64,
 		period_duration: i64,
 		start_time: i64,
 		end_time: i64,
+		cliff_time: Option<i64>,
 		category: u8,
+		transfer_requires_approval: bool,
 	)]
 	pub struct CreatePaymentStream<'info> {
 		#[account(mut)]
 		pub authority: Signer<'info>,
 
 		#[account(
+			mut,
 			seeds = [
 				b"treasury",
 				name.as_bytes().as_ref(),
 			],
 			bump,
+			constraint = stream_id == treasury.stream_count @ TreasuryManagementError::InvalidStreamId,
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			init,
-			space=186,
+			space=236,
 			payer=authority,
 			seeds = [
 				b"payment_stream",
 				treasury.key().as_ref(),
-				recipient.as_ref(),
+				stream_id.to_le_bytes().as_ref(),
 			],
 			bump,
 		)]
@@ -52,31 +69,82 @@ use anchor_spl::{
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` payment_stream: [PaymentStream] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury. The stream's full value counts against its spending limit.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
+/// - stream_id: [u64] Must equal the treasury's `stream_count`.
 /// - recipient: [Pubkey] 
 /// - token_mint: [Pubkey] 
 /// - amount_per_period: [u64] 
 /// - period_duration: [i64] 
 /// - start_time: [i64] 
 /// - end_time: [i64] 
+/// - cliff_time: [Option<i64>] Nothing is claimable before it; defaults to `start_time`.
 /// - category: [u8] 
+/// - transfer_requires_approval: [bool] Whether `transfer_stream_recipient` also needs treasury approval.
 pub fn handler(
 	ctx: Context<CreatePaymentStream>,
 	name: String,
+	stream_id: u64,
 	recipient: Pubkey,
 	token_mint: Pubkey,
 	amount_per_period: u64,
 	period_duration: i64,
 	start_time: i64,
 	end_time: i64,
+	cliff_time: Option<i64>,
 	category: u8,
+	transfer_requires_approval: bool,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	let cliff_time = cliff_time.unwrap_or(start_time);
+	PaymentStream::validate_schedule(
+		amount_per_period,
+		period_duration,
+		start_time,
+		end_time,
+		cliff_time,
+	)?;
+
+	let accounts = &mut *ctx.accounts;
+	let authority = accounts.authority.key();
+
+	let stream = &mut accounts.payment_stream;
+	stream.treasury = accounts.treasury.key();
+	stream.id = accounts.treasury.next_stream_id()?;
+	stream.recipient = recipient;
+	stream.token_mint = token_mint;
+	stream.amount_per_period = amount_per_period;
+	stream.period_duration = period_duration;
+	stream.start_time = start_time;
+	stream.end_time = end_time;
+	stream.cliff_time = cliff_time;
+	stream.accrued = 0;
+	stream.accrued_until = start_time;
+	stream.is_paused = false;
+	stream.last_payment_time = start_time;
+	stream.total_paid = 0;
+	stream.is_active = true;
+	stream.category = category;
+	stream.created_by = authority;
+	stream.transfer_requires_approval = transfer_requires_approval;
+	stream.budget = stream.total_amount()?;
+
+	stream.charge_period = accounts.treasury.record_spending(
+		&authority,
+		accounts.authority_role.as_deref_mut(),
+		stream.budget,
+	)?;
+
 	Ok(())
 }
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -21,9 +22,11 @@ use anchor_spl::{
 		recipient: Pubkey,
 	)]
 	pub struct CreateProposal<'info> {
+		#[account(mut)]
 		pub proposer: Signer<'info>,
 
 		#[account(
+			mut,
 			seeds = [
 				b"treasury",
 				name.as_bytes().as_ref(),
@@ -32,9 +35,19 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				proposer.key().as_ref(),
+			],
+			bump,
+		)]
+		pub proposer_role: Option<Account<'info, Role>>,
+
 		#[account(
 			init,
-			space=1421,
+			space=1504,
 			payer=proposer,
 			seeds = [
 				b"proposal",
@@ -50,9 +63,10 @@ use anchor_spl::{
 
 /// Accounts:
 /// 0. `[signer]` proposer: [AccountInfo] 
-/// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` proposal: [Proposal] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` proposer_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
@@ -72,7 +86,30 @@ pub fn handler(
 	token_mint: Pubkey,
 	recipient: Pubkey,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.proposer.key(),
+		ctx.accounts.proposer_role.as_deref(),
+		Permission::Propose,
+	)?;
+
+	Proposal::validate_text(&title, &description)?;
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+	let proposal = &mut ctx.accounts.proposal;
+
+	proposal.open(
+		&mut ctx.accounts.treasury,
+		ctx.accounts.proposer.key(),
+		title,
+		description,
+		Clock::get()?.unix_timestamp,
+	)?;
+	proposal.kind = ProposalKind::Transfer;
+	proposal.amount = amount;
+	proposal.token_mint = token_mint;
+	proposal.recipient = recipient;
+	proposal.instructions = Vec::new();
+	proposal.config = None;
+
 	Ok(())
 }
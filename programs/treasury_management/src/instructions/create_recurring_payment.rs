@@ -18,8 +18,12 @@ use anchor_spl::{
 		amount: u64,
 		interval: i64,
 		category: u8,
+		max_payments: Option<u32>,
+		end_date: Option<i64>,
+		schedule: RecurringSchedule,
 	)]
 	pub struct CreateRecurringPayment<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
 		#[account(
@@ -31,9 +35,19 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			init,
-			space=162,
+			space=217,
 			payer=authority,
 			seeds = [
 				b"recurring",
@@ -50,16 +64,20 @@ use anchor_spl::{
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` recurring_payment: [RecurringPayment] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
 /// - recipient: [Pubkey] 
 /// - token_mint: [Pubkey] 
 /// - amount: [u64] 
-/// - interval: [i64] 
+/// - interval: [i64] Seconds between installments on an `Interval` schedule; ignored otherwise.
 /// - category: [u8] 
+/// - max_payments: [Option<u32>] Deactivate after this many installments.
+/// - end_date: [Option<i64>] Deactivate once the next installment would fall after this time.
+/// - schedule: [RecurringSchedule] A fixed interval or a calendar schedule.
 pub fn handler(
 	ctx: Context<CreateRecurringPayment>,
 	name: String,
@@ -68,8 +86,42 @@ pub fn handler(
 	amount: u64,
 	interval: i64,
 	category: u8,
+	max_payments: Option<u32>,
+	end_date: Option<i64>,
+	schedule: RecurringSchedule,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	schedule.validate(interval)?;
+	let first_payment_date = schedule.first_payment_date(Clock::get()?.unix_timestamp, interval)?;
+	RecurringPayment::validate_schedule(
+		amount,
+		max_payments,
+		end_date,
+		first_payment_date,
+	)?;
+
+	let recurring_payment = &mut ctx.accounts.recurring_payment;
+	recurring_payment.treasury = ctx.accounts.treasury.key();
+	recurring_payment.recipient = recipient;
+	recurring_payment.token_mint = token_mint;
+	recurring_payment.amount = amount;
+	recurring_payment.interval = interval;
+	recurring_payment.next_payment_date = first_payment_date;
+	recurring_payment.is_active = true;
+	recurring_payment.created_by = ctx.accounts.authority.key();
+	recurring_payment.category = category;
+	recurring_payment.payments_made = 0;
+	recurring_payment.max_payments = max_payments;
+	recurring_payment.end_date = end_date;
+	recurring_payment.schedule = schedule;
+	recurring_payment.is_paused = false;
+	recurring_payment.destination = None;
+
 	Ok(())
 }
@@ -0,0 +1,75 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		delegate: Pubkey,
+		expires_at: Option<i64>,
+	)]
+	pub struct DelegateVote<'info> {
+		#[account(mut)]
+		pub delegator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			init_if_needed,
+			space=113,
+			payer=delegator,
+			seeds = [
+				b"vote_delegation",
+				treasury.key().as_ref(),
+				delegator.key().as_ref(),
+			],
+			bump,
+		)]
+		pub delegation: Account<'info, VoteDelegation>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` delegator: [AccountInfo] A treasury signer.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` delegation: [VoteDelegation] Created on first use; delegating again replaces it.
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - delegate: [Pubkey] Allowed to vote on the delegator's behalf.
+/// - expires_at: [Option<i64>] Timestamp after which the delegation lapses.
+pub fn handler(
+	ctx: Context<DelegateVote>,
+	name: String,
+	delegate: Pubkey,
+	expires_at: Option<i64>,
+) -> Result<()> {
+	let delegator = ctx.accounts.delegator.key();
+	ctx.accounts.treasury.require_signer(&delegator)?;
+
+	let now = Clock::get()?.unix_timestamp;
+	require!(
+		delegate != delegator && expires_at.is_none_or(|expires_at| expires_at > now),
+		TreasuryManagementError::InvalidDelegation
+	);
+
+	let delegation = &mut ctx.accounts.delegation;
+	delegation.treasury = ctx.accounts.treasury.key();
+	delegation.delegator = delegator;
+	delegation.delegate = delegate;
+	delegation.expires_at = expires_at;
+
+	Ok(())
+}
@@ -0,0 +1,130 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		amount: u64,
+	)]
+	pub struct DepositGovernanceTokens<'info> {
+		#[account(mut)]
+		pub voter: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		pub governance_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			token::mint = governance_mint,
+			token::authority = voter,
+		)]
+		pub source: Account<'info, TokenAccount>,
+
+		/// CHECK: holds no data; only signs for the treasury's escrow vaults
+		#[account(
+			seeds = [
+				b"escrow_authority",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub escrow_authority: UncheckedAccount<'info>,
+
+		#[account(
+			init_if_needed,
+			payer=voter,
+			seeds = [
+				b"governance_escrow",
+				treasury.key().as_ref(),
+				governance_mint.key().as_ref(),
+			],
+			bump,
+			token::mint = governance_mint,
+			token::authority = escrow_authority,
+			token::token_program = csl_spl_token_v0_0_0,
+		)]
+		pub escrow_vault: Account<'info, TokenAccount>,
+
+		#[account(
+			init_if_needed,
+			space=88,
+			payer=voter,
+			seeds = [
+				b"voter_escrow",
+				treasury.key().as_ref(),
+				voter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub voter_escrow: Account<'info, VoterEscrow>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+	impl<'info> DepositGovernanceTokens<'info> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.source.to_account_info(),
+						to: self.escrow_vault.to_account_info(),
+						authority: self.voter.to_account_info()
+					}
+				),
+				amount, 
+			)
+		}
+	}
+
+
+/// Accounts:
+/// 0. `[writable, signer]` voter: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` governance_mint: [Mint] The treasury's governance mint.
+/// 3. `[writable]` source: [TokenAccount] The voter's governance token account.
+/// 4. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 5. `[writable]` escrow_vault: [TokenAccount] Holds every voter's escrowed tokens of this treasury's current governance mint.
+/// 6. `[writable]` voter_escrow: [VoterEscrow] The voter's escrowed balance, created on first deposit.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - amount: [u64] 
+pub fn handler(
+	ctx: Context<DepositGovernanceTokens>,
+	name: String,
+	amount: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_governance_mint(&ctx.accounts.governance_mint.key())?;
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
+
+	let treasury = &mut ctx.accounts.treasury;
+	treasury.governance_deposits = treasury.governance_deposits
+		.checked_add(amount)
+		.ok_or(TreasuryManagementError::MathOverflow)?;
+
+	let voter_escrow = &mut ctx.accounts.voter_escrow;
+	voter_escrow.treasury = treasury.key();
+	voter_escrow.owner = ctx.accounts.voter.key();
+	voter_escrow.deposit(amount)
+}
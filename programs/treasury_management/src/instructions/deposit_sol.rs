@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -30,11 +31,40 @@ use anchor_spl::{
 			bump,
 		)]
 		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"sol_vault",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub sol_vault: SystemAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+	impl<'info> DepositSol<'info> {
+		pub fn cpi_system_transfer(&self, amount: u64) -> Result<()> {
+			anchor_lang::system_program::transfer(
+				CpiContext::new(self.system_program.to_account_info(),
+					anchor_lang::system_program::Transfer {
+						from: self.depositor.to_account_info(),
+						to: self.sol_vault.to_account_info(),
+					}
+				),
+				amount,
+			)
+		}
 	}
 
+
 /// Accounts:
 /// 0. `[writable, signer]` depositor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[writable]` sol_vault: [AccountInfo] System-owned PDA holding the treasury's lamports.
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for the lamport transfer
 ///
 /// Data:
 /// - name: [String] 
@@ -44,7 +74,14 @@ pub fn handler(
 	name: String,
 	amount: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+	ctx.accounts.cpi_system_transfer(amount)?;
+
+	let treasury = &mut ctx.accounts.treasury;
+	treasury.total_deposited = treasury.total_deposited
+		.checked_add(amount)
+		.ok_or(TreasuryManagementError::MathOverflow)?;
+
 	Ok(())
 }
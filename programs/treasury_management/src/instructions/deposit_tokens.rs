@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -32,20 +33,17 @@ use anchor_spl::{
 
 		#[account(
 			mut,
+			token::mint = token_mint,
+			token::authority = depositor,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub source: UncheckedAccount<'info>,
+		pub source: Account<'info, TokenAccount>,
 
 		#[account(
 			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub destination: UncheckedAccount<'info>,
-
-		#[account(
-			owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
-		)]
-		pub authority: Signer<'info>,
+		pub token_vault: Account<'info, TokenAccount>,
 
 		pub csl_spl_token_v0_0_0: Program<'info, Token>,
 	}
@@ -56,8 +54,8 @@ use anchor_spl::{
 				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
 					anchor_spl::token::Transfer {
 						from: self.source.to_account_info(),
-						to: self.destination.to_account_info(),
-						authority: self.authority.to_account_info()
+						to: self.token_vault.to_account_info(),
+						authority: self.depositor.to_account_info()
 					}
 				),
 				amount, 
@@ -70,10 +68,9 @@ use anchor_spl::{
 /// 0. `[signer]` depositor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
 /// 2. `[]` token_mint: [Mint] 
-/// 3. `[writable]` source: [AccountInfo] The source account.
-/// 4. `[writable]` destination: [AccountInfo] The destination account.
-/// 5. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 3. `[writable]` source: [TokenAccount] The depositor's token account.
+/// 4. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 5. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
@@ -83,12 +80,9 @@ pub fn handler(
 	name: String,
 	amount: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	// Cpi calls wrappers
-	ctx.accounts.cpi_csl_spl_token_transfer(
-		Default::default(),
-	)?;
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount)?;
 
 	Ok(())
 }
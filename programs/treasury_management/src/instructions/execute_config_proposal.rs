@@ -0,0 +1,69 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct ExecuteConfigProposal<'info> {
+		pub executor: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` executor: [AccountInfo] Any current treasury signer.
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] 
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler(
+	ctx: Context<ExecuteConfigProposal>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_signer(&ctx.accounts.executor.key())?;
+
+	// Not gated on the pause flag, so signers can still rotate a
+	// compromised key while the treasury is paused.
+	let now = Clock::get()?.unix_timestamp;
+	let proposal = &mut ctx.accounts.proposal;
+	proposal.require_kind(ProposalKind::ConfigChange)?;
+	proposal.require_executable(&ctx.accounts.treasury, now)?;
+
+	let config = proposal.config
+		.clone()
+		.ok_or(TreasuryManagementError::ProposalKindMismatch)?;
+	ctx.accounts.treasury.apply_config(config)?;
+
+	proposal.status = ProposalStatus::Executed;
+	proposal.executed_at = now;
+
+	Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+	instruction::{AccountMeta, Instruction},
+	program::invoke_signed,
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct ExecuteInstructionProposal<'info> {
+		pub executor: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				executor.key().as_ref(),
+			],
+			bump,
+		)]
+		pub executor_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` executor: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` executor_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+///    4..n. Remaining accounts: every program and account referenced by the proposal's instructions, other than the treasury itself.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler<'info>(
+	ctx: Context<'_, '_, 'info, 'info, ExecuteInstructionProposal<'info>>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.executor.key(),
+		ctx.accounts.executor_role.as_deref(),
+		Permission::ExecutePayments,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	let now = Clock::get()?.unix_timestamp;
+	ctx.accounts.proposal.require_kind(ProposalKind::Instructions)?;
+	ctx.accounts.proposal.require_executable(&ctx.accounts.treasury, now)?;
+
+	let treasury_info = ctx.accounts.treasury.to_account_info();
+	// Only the treasury signs; escrow vaults answer to `escrow_authority` instead.
+	let treasury_bump = [ctx.bumps.treasury];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"treasury",
+		name.as_bytes(),
+		&treasury_bump,
+	]];
+
+	let find_account = |key: &Pubkey| -> Result<AccountInfo<'info>> {
+		if *key == treasury_info.key() {
+			return Ok(treasury_info.clone());
+		}
+
+		ctx.remaining_accounts
+			.iter()
+			.find(|info| info.key == key)
+			.cloned()
+			.ok_or_else(|| error!(TreasuryManagementError::MissingProposalAccount))
+	};
+
+	for proposal_ix in ctx.accounts.proposal.instructions.iter() {
+		let mut metas = Vec::with_capacity(proposal_ix.accounts.len());
+		let mut infos = Vec::with_capacity(proposal_ix.accounts.len() + 1);
+		for meta in proposal_ix.accounts.iter() {
+			metas.push(AccountMeta {
+				pubkey: meta.pubkey,
+				is_signer: meta.is_signer,
+				is_writable: meta.is_writable,
+			});
+			infos.push(find_account(&meta.pubkey)?);
+		}
+		infos.push(find_account(&proposal_ix.program_id)?);
+
+		let instruction = Instruction {
+			program_id: proposal_ix.program_id,
+			accounts: metas,
+			data: proposal_ix.data.clone(),
+		};
+		invoke_signed(&instruction, &infos, signer_seeds)?;
+	}
+
+	let proposal = &mut ctx.accounts.proposal;
+	proposal.status = ProposalStatus::Executed;
+	proposal.executed_at = now;
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -13,7 +14,7 @@ use anchor_spl::{
 	#[derive(Accounts)]
 	#[instruction(
 		name: String,
-		recipient: Pubkey,
+		stream_id: u64,
 	)]
 	pub struct ExecuteStreamPayment<'info> {
 		pub executor: Signer<'info>,
@@ -28,48 +29,59 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				executor.key().as_ref(),
+			],
+			bump,
+		)]
+		pub executor_role: Option<Account<'info, Role>>,
+
 		#[account(
 			mut,
 			seeds = [
 				b"payment_stream",
 				treasury.key().as_ref(),
-				recipient.as_ref(),
+				stream_id.to_le_bytes().as_ref(),
 			],
 			bump,
 		)]
 		pub payment_stream: Account<'info, PaymentStream>,
 
-		pub token_mint: Account<'info, Mint>,
-
 		#[account(
-			mut,
+			address = payment_stream.token_mint @ TreasuryManagementError::InvalidTokenMint,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub source: UncheckedAccount<'info>,
+		pub token_mint: Account<'info, Mint>,
 
 		#[account(
 			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub destination: UncheckedAccount<'info>,
+		pub token_vault: Account<'info, TokenAccount>,
 
 		#[account(
-			owner=Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap(),
+			mut,
+			token::mint = token_mint,
+			token::authority = payment_stream.recipient,
 		)]
-		pub authority: Signer<'info>,
+		pub destination: Account<'info, TokenAccount>,
 
 		pub csl_spl_token_v0_0_0: Program<'info, Token>,
 	}
 
 	impl<'info> ExecuteStreamPayment<'info> {
-		pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
 			anchor_spl::token::transfer(
-				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
 					anchor_spl::token::Transfer {
-						from: self.source.to_account_info(),
+						from: self.token_vault.to_account_info(),
 						to: self.destination.to_account_info(),
-						authority: self.authority.to_account_info()
-					}
+						authority: self.treasury.to_account_info()
+					},
+					signer_seeds,
 				),
 				amount, 
 			)
@@ -80,27 +92,42 @@ use anchor_spl::{
 /// Accounts:
 /// 0. `[signer]` executor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` payment_stream: [PaymentStream] 
-/// 3. `[]` token_mint: [Mint] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 2. `[]` executor_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
 /// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
-/// - recipient: [Pubkey] 
+/// - stream_id: [u64] 
 pub fn handler(
 	ctx: Context<ExecuteStreamPayment>,
 	name: String,
-	recipient: Pubkey,
+	stream_id: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	// Cpi calls wrappers
-	ctx.accounts.cpi_csl_spl_token_transfer(
-		Default::default(),
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.executor.key(),
+		ctx.accounts.executor_role.as_deref(),
+		Permission::ExecutePayments,
 	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	let amount = ctx.accounts.payment_stream.record_payout(Clock::get()?.unix_timestamp)?;
+	require!(
+		ctx.accounts.token_vault.amount >= amount,
+		TreasuryManagementError::InsufficientFunds
+	);
+
+	let treasury_bump = [ctx.bumps.treasury];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"treasury",
+		name.as_bytes(),
+		&treasury_bump,
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)?;
 
 	Ok(())
 }
@@ -0,0 +1,69 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct ExpireProposal<'info> {
+		pub caller: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			close=proposer,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		#[account(
+			mut,
+			address = proposal.proposer,
+		)]
+		pub proposer: SystemAccount<'info>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` caller: [AccountInfo] Anyone may expire a stale proposal.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] Closed, with its rent refunded to `proposer`.
+/// 3. `[writable]` proposer: [AccountInfo] The proposal's original proposer.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler(
+	ctx: Context<ExpireProposal>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	let proposal = &mut ctx.accounts.proposal;
+	require!(
+		proposal.is_stale(Clock::get()?.unix_timestamp),
+		TreasuryManagementError::ProposalNotExpired
+	);
+
+	proposal.status = ProposalStatus::Expired;
+	msg!("Proposal {} on treasury {} expired", id, name);
+
+	// The proposal account is closed by its `close` constraint once the handler returns.
+	Ok(())
+}
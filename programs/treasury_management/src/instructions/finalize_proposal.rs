@@ -0,0 +1,68 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::Mint;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct FinalizeProposal<'info> {
+		pub caller: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		#[account(
+			address = proposal.governance_mint.unwrap_or_default() @ TreasuryManagementError::GovernanceModeMismatch,
+		)]
+		pub governance_mint: Account<'info, Mint>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` caller: [AccountInfo] Anyone may finalize once voting has closed.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] A token-weighted proposal.
+/// 3. `[]` governance_mint: [Mint] The proposal's governance mint, whose supply sets the quorum.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler(
+	ctx: Context<FinalizeProposal>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	let proposal = &mut ctx.accounts.proposal;
+	proposal.finalize(
+		&ctx.accounts.treasury,
+		ctx.accounts.governance_mint.supply,
+		Clock::get()?.unix_timestamp,
+	)?;
+
+	msg!("Proposal {} on treasury {} finalized as {:?}", id, name, proposal.status);
+
+	Ok(())
+}
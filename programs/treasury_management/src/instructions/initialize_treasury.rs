@@ -14,6 +14,7 @@ use anchor_spl::{
 	#[instruction(
 		name: String,
 		signers: Vec<Pubkey>,
+		signer_weights: Vec<u8>,
 		threshold: u8,
 		admin_limit: u64,
 		treasurer_limit: u64,
@@ -28,7 +29,7 @@ use anchor_spl::{
 
 		#[account(
 			init,
-			space=479,
+			space=566,
 			payer=authority,
 			seeds = [
 				b"treasury",
@@ -49,7 +50,8 @@ use anchor_spl::{
 /// Data:
 /// - name: [String] 
 /// - signers: [Vec<Pubkey>] 
-/// - threshold: [u8] 
+/// - signer_weights: [Vec<u8>] Voting weight of each signer, in the same order.
+/// - threshold: [u8] Total signer weight needed to approve a proposal.
 /// - admin_limit: [u64] 
 /// - treasurer_limit: [u64] 
 /// - contributor_limit: [u64] 
@@ -60,6 +62,7 @@ pub fn handler(
 	ctx: Context<InitializeTreasury>,
 	name: String,
 	signers: Vec<Pubkey>,
+	signer_weights: Vec<u8>,
 	threshold: u8,
 	admin_limit: u64,
 	treasurer_limit: u64,
@@ -68,7 +71,38 @@ pub fn handler(
 	auto_stake: bool,
 	stake_target_percentage: u8,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	Treasury::validate_name(&name)?;
+	Treasury::validate_signers(&signers, &signer_weights, threshold)?;
+	Treasury::validate_stake_target_percentage(stake_target_percentage)?;
+
+	let treasury = &mut ctx.accounts.treasury;
+
+	treasury.name = name;
+	treasury.authority = ctx.accounts.authority.key();
+	treasury.signers = signers;
+	treasury.signer_weights = signer_weights;
+	treasury.threshold = threshold;
+	treasury.is_paused = false;
+	treasury.total_deposited = 0;
+	treasury.total_withdrawn = 0;
+	treasury.created_at = Clock::get()?.unix_timestamp;
+	treasury.admin_limit = admin_limit;
+	treasury.treasurer_limit = treasurer_limit;
+	treasury.contributor_limit = contributor_limit;
+	treasury.reset_period = reset_period;
+	treasury.auto_stake = auto_stake;
+	treasury.stake_target_percentage = stake_target_percentage;
+	treasury.whitelist_enabled = false;
+	treasury.voting_period = DEFAULT_VOTING_PERIOD;
+	treasury.execution_grace_period = DEFAULT_EXECUTION_GRACE_PERIOD;
+	treasury.execution_delay = DEFAULT_EXECUTION_DELAY;
+	treasury.execution_delay_amount_step = 0;
+	treasury.governance_mint = None;
+	treasury.quorum_percentage = DEFAULT_QUORUM_PERCENTAGE;
+	treasury.approval_percentage = DEFAULT_APPROVAL_PERCENTAGE;
+	treasury.governance_deposits = 0;
+	treasury.stream_count = 0;
+	treasury.proposal_count = 0;
+
 	Ok(())
 }
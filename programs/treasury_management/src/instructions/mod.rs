@@ -9,17 +9,43 @@ pub mod assign_role;
 pub mod remove_role;
 pub mod create_payment_stream;
 pub mod execute_stream_payment;
+pub mod claim_stream;
 pub mod cancel_payment_stream;
+pub mod pause_payment_stream;
+pub mod resume_payment_stream;
+pub mod update_payment_stream;
+pub mod top_up_payment_stream;
+pub mod transfer_stream_recipient;
 pub mod create_milestone_payment;
 pub mod complete_milestone;
+pub mod cancel_milestone_payment;
 pub mod create_recurring_payment;
 pub mod execute_recurring_payment;
+pub mod pause_recurring_payment;
+pub mod resume_recurring_payment;
+pub mod skip_recurring_payment;
+pub mod update_recurring_payment;
+pub mod cancel_recurring_payment;
 pub mod batch_transfer;
 pub mod stake_sol_for_yield;
 pub mod unstake_sol;
 pub mod create_proposal;
 pub mod vote_on_proposal;
+pub mod revoke_vote;
+pub mod delegate_vote;
+pub mod revoke_delegation;
+pub mod deposit_governance_tokens;
+pub mod withdraw_governance_tokens;
+pub mod cast_token_vote;
+pub mod revoke_token_vote;
+pub mod finalize_proposal;
 pub mod execute_proposal;
+pub mod create_instruction_proposal;
+pub mod execute_instruction_proposal;
+pub mod create_config_proposal;
+pub mod execute_config_proposal;
+pub mod expire_proposal;
+pub mod cancel_proposal;
 pub mod emergency_pause;
 pub mod resume_operations;
 pub mod add_whitelist_recipient;
@@ -35,17 +61,43 @@ pub use assign_role::*;
 pub use remove_role::*;
 pub use create_payment_stream::*;
 pub use execute_stream_payment::*;
+pub use claim_stream::*;
 pub use cancel_payment_stream::*;
+pub use pause_payment_stream::*;
+pub use resume_payment_stream::*;
+pub use update_payment_stream::*;
+pub use top_up_payment_stream::*;
+pub use transfer_stream_recipient::*;
 pub use create_milestone_payment::*;
 pub use complete_milestone::*;
+pub use cancel_milestone_payment::*;
 pub use create_recurring_payment::*;
 pub use execute_recurring_payment::*;
+pub use pause_recurring_payment::*;
+pub use resume_recurring_payment::*;
+pub use skip_recurring_payment::*;
+pub use update_recurring_payment::*;
+pub use cancel_recurring_payment::*;
 pub use batch_transfer::*;
 pub use stake_sol_for_yield::*;
 pub use unstake_sol::*;
 pub use create_proposal::*;
 pub use vote_on_proposal::*;
+pub use revoke_vote::*;
+pub use delegate_vote::*;
+pub use revoke_delegation::*;
+pub use deposit_governance_tokens::*;
+pub use withdraw_governance_tokens::*;
+pub use cast_token_vote::*;
+pub use revoke_token_vote::*;
+pub use finalize_proposal::*;
 pub use execute_proposal::*;
+pub use create_instruction_proposal::*;
+pub use execute_instruction_proposal::*;
+pub use create_config_proposal::*;
+pub use execute_config_proposal::*;
+pub use expire_proposal::*;
+pub use cancel_proposal::*;
 pub use emergency_pause::*;
 pub use resume_operations::*;
 pub use add_whitelist_recipient::*;
@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		stream_id: u64,
+	)]
+	pub struct PausePaymentStream<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payment_stream",
+				treasury.key().as_ref(),
+				stream_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub payment_stream: Account<'info, PaymentStream>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+pub fn handler(
+	ctx: Context<PausePaymentStream>,
+	name: String,
+	stream_id: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+
+	ctx.accounts.payment_stream.pause(Clock::get()?.unix_timestamp)?;
+
+	Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		recipient: Pubkey,
+	)]
+	pub struct PauseRecurringPayment<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"recurring",
+				treasury.key().as_ref(),
+				recipient.as_ref(),
+			],
+			bump,
+		)]
+		pub recurring_payment: Account<'info, RecurringPayment>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+pub fn handler(
+	ctx: Context<PauseRecurringPayment>,
+	name: String,
+	recipient: Pubkey,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+
+	let before = (*ctx.accounts.recurring_payment).clone();
+	ctx.accounts.recurring_payment.pause()?;
+
+	emit!(RecurringPaymentChanged::new(
+		RecurringPaymentAction::Paused,
+		ctx.accounts.authority.key(),
+		ctx.accounts.recurring_payment.key(),
+		&before,
+		&ctx.accounts.recurring_payment,
+	));
+
+	Ok(())
+}
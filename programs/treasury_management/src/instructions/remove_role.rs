@@ -16,6 +16,7 @@ use anchor_spl::{
 		user: Pubkey,
 	)]
 	pub struct RemoveRole<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
 		#[account(
@@ -26,11 +27,35 @@ use anchor_spl::{
 			bump,
 		)]
 		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			close=authority,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				user.as_ref(),
+			],
+			bump,
+		)]
+		pub role: Account<'info, Role>,
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` role: [Role] Closed, with its rent refunded to `authority`.
 ///
 /// Data:
 /// - name: [String] 
@@ -40,7 +65,12 @@ pub fn handler(
 	name: String,
 	user: Pubkey,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ManageRoles,
+	)?;
+
+	// The role account is closed by its `close` constraint once the handler returns.
 	Ok(())
 }
@@ -16,6 +16,7 @@ use anchor_spl::{
 		recipient: Pubkey,
 	)]
 	pub struct RemoveWhitelistRecipient<'info> {
+		#[account(mut)]
 		pub authority: Signer<'info>,
 
 		#[account(
@@ -27,6 +28,16 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		#[account(
 			mut,
 			close=authority,
@@ -41,9 +52,10 @@ use anchor_spl::{
 	}
 
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
+/// 0. `[writable, signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` whitelist_entry: [WhitelistedRecipient] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` whitelist_entry: [WhitelistedRecipient] 
 ///
 /// Data:
 /// - name: [String] 
@@ -53,6 +65,12 @@ pub fn handler(
 	name: String,
 	recipient: Pubkey,
 ) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ManageRoles,
+	)?;
+
     // Implement your business logic here...
 	
 	Ok(())
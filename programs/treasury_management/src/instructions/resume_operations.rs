@@ -38,7 +38,9 @@ pub fn handler(
 	ctx: Context<ResumeOperations>,
 	name: String,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_authority(&ctx.accounts.authority.key())?;
+
+	ctx.accounts.treasury.is_paused = false;
+
 	Ok(())
 }
@@ -0,0 +1,57 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+	)]
+	pub struct RevokeDelegation<'info> {
+		#[account(mut)]
+		pub delegator: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			close=delegator,
+			seeds = [
+				b"vote_delegation",
+				treasury.key().as_ref(),
+				delegator.key().as_ref(),
+			],
+			bump,
+		)]
+		pub delegation: Account<'info, VoteDelegation>,
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` delegator: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` delegation: [VoteDelegation] Closed, with its rent refunded to `delegator`.
+///
+/// Data:
+/// - name: [String] 
+pub fn handler(
+	ctx: Context<RevokeDelegation>,
+	name: String,
+) -> Result<()> {
+	msg!(
+		"Vote delegation from {} to {} on treasury {} revoked",
+		ctx.accounts.delegator.key(),
+		ctx.accounts.delegation.delegate,
+		name,
+	);
+
+	// The delegation account is closed by its `close` constraint once the handler returns.
+	Ok(())
+}
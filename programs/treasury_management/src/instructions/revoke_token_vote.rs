@@ -0,0 +1,69 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct RevokeTokenVote<'info> {
+		#[account(mut)]
+		pub voter: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+
+		#[account(
+			mut,
+			close=voter,
+			seeds = [
+				b"vote_record",
+				proposal.key().as_ref(),
+				voter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub vote_record: Account<'info, VoteRecord>,
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` voter: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] A token-weighted proposal.
+/// 3. `[writable]` vote_record: [VoteRecord] Closed, with its rent refunded to `voter`.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler(
+	ctx: Context<RevokeTokenVote>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	// The escrow lock stays in place until voting closes, so the same
+	// tokens cannot be withdrawn and voted again from another account.
+	ctx.accounts.proposal.revoke_token_vote(
+		&ctx.accounts.vote_record,
+		Clock::get()?.unix_timestamp,
+	)
+}
@@ -0,0 +1,54 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		id: u64,
+	)]
+	pub struct RevokeVote<'info> {
+		pub voter: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"proposal",
+				treasury.key().as_ref(),
+				id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub proposal: Account<'info, Proposal>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` voter: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] 
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+pub fn handler(
+	ctx: Context<RevokeVote>,
+	name: String,
+	id: u64,
+) -> Result<()> {
+	ctx.accounts.proposal.revoke_vote(
+		ctx.accounts.voter.key(),
+		&ctx.accounts.treasury,
+		Clock::get()?.unix_timestamp,
+	)
+}
@@ -0,0 +1,86 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		stream_id: u64,
+		amount: u64,
+	)]
+	pub struct TopUpPaymentStream<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payment_stream",
+				treasury.key().as_ref(),
+				stream_id.to_le_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub payment_stream: Account<'info, PaymentStream>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury. The top-up counts against its spending limit.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+/// - amount: [u64] Added to the stream's budget.
+pub fn handler(
+	ctx: Context<TopUpPaymentStream>,
+	name: String,
+	stream_id: u64,
+	amount: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	let accounts = &mut *ctx.accounts;
+	accounts.payment_stream.top_up(amount, Clock::get()?.unix_timestamp)?;
+	accounts.treasury.record_spending(
+		&accounts.authority.key(),
+		accounts.authority_role.as_deref_mut(),
+		amount,
+	)?;
+
+	Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		stream_id: u64,
+	)]
+	pub struct TransferStreamRecipient<'info> {
+		pub recipient: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"payment_stream",
+				treasury.key().as_ref(),
+				stream_id.to_le_bytes().as_ref(),
+			],
+			bump,
+			has_one = recipient @ TreasuryManagementError::InsufficientPermissions,
+		)]
+		pub payment_stream: Account<'info, PaymentStream>,
+
+		pub approver: Option<Signer<'info>>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				approver.as_ref().map(|approver| approver.key()).unwrap_or_default().as_ref(),
+			],
+			bump,
+		)]
+		pub approver_role: Option<Account<'info, Role>>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` recipient: [AccountInfo] The stream's current recipient.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` payment_stream: [PaymentStream] 
+/// 3. `[signer]` approver: [AccountInfo] Optional, required when the stream's `transfer_requires_approval` is set.
+/// 4. `[]` approver_role: [Role] Optional, the approver's role on this treasury.
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+/// - new_recipient: [Pubkey] 
+pub fn handler(
+	ctx: Context<TransferStreamRecipient>,
+	name: String,
+	stream_id: u64,
+	new_recipient: Pubkey,
+) -> Result<()> {
+	if ctx.accounts.payment_stream.transfer_requires_approval {
+		let approver = ctx.accounts.approver
+			.as_ref()
+			.ok_or(TreasuryManagementError::StreamTransferNotApproved)?;
+		ctx.accounts.treasury.require_permission(
+			&approver.key(),
+			ctx.accounts.approver_role.as_deref(),
+			Permission::CreateStreams,
+		)?;
+	}
+
+	ctx.accounts.payment_stream.transfer_recipient(new_recipient)?;
+	msg!("Payment stream {} on treasury {} now pays {}", stream_id, name, new_recipient);
+
+	Ok(())
+}
@@ -27,11 +27,22 @@ use anchor_spl::{
 			bump,
 		)]
 		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
 ///
 /// Data:
 /// - name: [String] 
@@ -41,6 +52,13 @@ pub fn handler(
 	name: String,
 	amount: u64,
 ) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ExecutePayments,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
     // Implement your business logic here...
 	
 	Ok(())
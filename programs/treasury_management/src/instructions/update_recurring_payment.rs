@@ -0,0 +1,103 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+use std::str::FromStr;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		recipient: Pubkey,
+		amount: Option<u64>,
+		clear_destination: bool,
+	)]
+	pub struct UpdateRecurringPayment<'info> {
+		pub authority: Signer<'info>,
+
+		#[account(
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"recurring",
+				treasury.key().as_ref(),
+				recipient.as_ref(),
+			],
+			bump,
+		)]
+		pub recurring_payment: Account<'info, RecurringPayment>,
+
+		#[account(
+			token::mint = recurring_payment.token_mint,
+		)]
+		pub destination: Option<Account<'info, TokenAccount>>,
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[]` destination: [TokenAccount] Optional, a `token_mint` account to pay future installments into.
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+/// - amount: [Option<u64>] New amount per installment, if changing it.
+/// - clear_destination: [bool] Pay future installments to any `token_mint` account owned by `recipient` again; cannot be combined with `destination`.
+pub fn handler(
+	ctx: Context<UpdateRecurringPayment>,
+	name: String,
+	recipient: Pubkey,
+	amount: Option<u64>,
+	clear_destination: bool,
+) -> Result<()> {
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::CreateStreams,
+	)?;
+
+	let before = (*ctx.accounts.recurring_payment).clone();
+	let destination = match (ctx.accounts.destination.as_ref(), clear_destination) {
+		(Some(_), true) => return err!(TreasuryManagementError::ConflictingRecurringDestination),
+		(Some(destination), false) => Some(Some(destination.key())),
+		(None, true) => Some(None),
+		(None, false) => None,
+	};
+	ctx.accounts.recurring_payment.update_terms(amount, destination)?;
+
+	emit!(RecurringPaymentChanged::new(
+		RecurringPaymentAction::Updated,
+		ctx.accounts.authority.key(),
+		ctx.accounts.recurring_payment.key(),
+		&before,
+		&ctx.accounts.recurring_payment,
+	));
+
+	Ok(())
+}
@@ -14,6 +14,7 @@ use anchor_spl::{
 	#[instruction(
 		name: String,
 		new_signers: Vec<Pubkey>,
+		new_signer_weights: Vec<u8>,
 		new_threshold: u8,
 		admin_limit: u64,
 		treasurer_limit: u64,
@@ -21,6 +22,13 @@ use anchor_spl::{
 		reset_period: u64,
 		auto_stake: bool,
 		stake_target_percentage: u8,
+		voting_period: i64,
+		execution_grace_period: i64,
+		execution_delay: i64,
+		execution_delay_amount_step: u64,
+		governance_mint: Option<Pubkey>,
+		quorum_percentage: u8,
+		approval_percentage: u8,
 	)]
 	pub struct UpdateTreasuryConfig<'info> {
 		pub authority: Signer<'info>,
@@ -43,17 +51,26 @@ use anchor_spl::{
 /// Data:
 /// - name: [String] 
 /// - new_signers: [Vec<Pubkey>] 
-/// - new_threshold: [u8] 
+/// - new_signer_weights: [Vec<u8>] Voting weight of each new signer, in the same order.
+/// - new_threshold: [u8] Total signer weight needed to approve a proposal.
 /// - admin_limit: [u64] 
 /// - treasurer_limit: [u64] 
 /// - contributor_limit: [u64] 
 /// - reset_period: [u64] 
 /// - auto_stake: [bool] 
 /// - stake_target_percentage: [u8] 
+/// - voting_period: [i64] Seconds new proposals stay open for voting.
+/// - execution_grace_period: [i64] Seconds after voting closes to execute an approved proposal.
+/// - execution_delay: [i64] Seconds between approval and the earliest execution.
+/// - execution_delay_amount_step: [u64] Each full step of a proposal's amount adds another `execution_delay`; zero disables scaling.
+/// - governance_mint: [Option<Pubkey>] Mint whose escrowed holders vote on new proposals instead of the signers.
+/// - quorum_percentage: [u8] Share of the governance mint's supply that must vote.
+/// - approval_percentage: [u8] Share of the cast token weight that must vote in favour.
 pub fn handler(
 	ctx: Context<UpdateTreasuryConfig>,
 	name: String,
 	new_signers: Vec<Pubkey>,
+	new_signer_weights: Vec<u8>,
 	new_threshold: u8,
 	admin_limit: u64,
 	treasurer_limit: u64,
@@ -61,8 +78,33 @@ pub fn handler(
 	reset_period: u64,
 	auto_stake: bool,
 	stake_target_percentage: u8,
+	voting_period: i64,
+	execution_grace_period: i64,
+	execution_delay: i64,
+	execution_delay_amount_step: u64,
+	governance_mint: Option<Pubkey>,
+	quorum_percentage: u8,
+	approval_percentage: u8,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	Ok(())
+	ctx.accounts.treasury.require_authority(&ctx.accounts.authority.key())?;
+	ctx.accounts.treasury.require_single_signer()?;
+
+	ctx.accounts.treasury.apply_config(TreasuryConfig {
+		signers: new_signers,
+		signer_weights: new_signer_weights,
+		threshold: new_threshold,
+		admin_limit,
+		treasurer_limit,
+		contributor_limit,
+		reset_period,
+		auto_stake,
+		stake_target_percentage,
+		voting_period,
+		execution_grace_period,
+		execution_delay,
+		execution_delay_amount_step,
+		governance_mint,
+		quorum_percentage,
+		approval_percentage,
+	})
 }
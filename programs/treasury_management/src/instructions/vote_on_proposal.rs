@@ -28,6 +28,16 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				voter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub voter_role: Option<Account<'info, Role>>,
+
 		#[account(
 			mut,
 			seeds = [
@@ -38,12 +48,16 @@ use anchor_spl::{
 			bump,
 		)]
 		pub proposal: Account<'info, Proposal>,
+
+		pub delegation: Option<Account<'info, VoteDelegation>>,
 	}
 
 /// Accounts:
 /// 0. `[signer]` voter: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` proposal: [Proposal] 
+/// 2. `[]` voter_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` delegation: [VoteDelegation] Optional, when `voter` votes as a signer's delegate.
 ///
 /// Data:
 /// - name: [String] 
@@ -55,7 +69,32 @@ pub fn handler(
 	id: u64,
 	vote_for: bool,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	Ok(())
+	let treasury = &ctx.accounts.treasury;
+	let cast_by = ctx.accounts.voter.key();
+	let now = Clock::get()?.unix_timestamp;
+
+	// Whoever casts the vote needs the Vote permission, delegate or not.
+	treasury.require_permission(
+		&cast_by,
+		ctx.accounts.voter_role.as_deref(),
+		Permission::Vote,
+	)?;
+
+	// A delegate votes with the delegator's power, as if the delegator had voted.
+	let voter = match &ctx.accounts.delegation {
+		Some(delegation) => {
+			delegation.require_active(&treasury.key(), &cast_by, now)?;
+			delegation.delegator
+		}
+		None => cast_by,
+	};
+	treasury.require_signer(&voter)?;
+
+	ctx.accounts.proposal.record_vote(
+		voter,
+		cast_by,
+		vote_for,
+		treasury,
+		now,
+	)
 }
@@ -0,0 +1,127 @@
+use crate::*;
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+
+
+
+	#[derive(Accounts)]
+	#[instruction(
+		name: String,
+		amount: u64,
+	)]
+	pub struct WithdrawGovernanceTokens<'info> {
+		pub voter: Signer<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"treasury",
+				name.as_bytes().as_ref(),
+			],
+			bump,
+		)]
+		pub treasury: Account<'info, Treasury>,
+
+		pub governance_mint: Account<'info, Mint>,
+
+		#[account(
+			mut,
+			token::mint = governance_mint,
+			token::authority = voter,
+		)]
+		pub destination: Account<'info, TokenAccount>,
+
+		/// CHECK: holds no data; only signs for the treasury's escrow vaults
+		#[account(
+			seeds = [
+				b"escrow_authority",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub escrow_authority: UncheckedAccount<'info>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"governance_escrow",
+				treasury.key().as_ref(),
+				governance_mint.key().as_ref(),
+			],
+			bump,
+		)]
+		pub escrow_vault: Account<'info, TokenAccount>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"voter_escrow",
+				treasury.key().as_ref(),
+				voter.key().as_ref(),
+			],
+			bump,
+		)]
+		pub voter_escrow: Account<'info, VoterEscrow>,
+
+		pub csl_spl_token_v0_0_0: Program<'info, Token>,
+	}
+
+	impl<'info> WithdrawGovernanceTokens<'info> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_spl::token::transfer(
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
+					anchor_spl::token::Transfer {
+						from: self.escrow_vault.to_account_info(),
+						to: self.destination.to_account_info(),
+						authority: self.escrow_authority.to_account_info()
+					},
+					signer_seeds,
+				),
+				amount, 
+			)
+		}
+	}
+
+
+/// Accounts:
+/// 0. `[signer]` voter: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` governance_mint: [Mint] The treasury's governance mint.
+/// 3. `[writable]` destination: [TokenAccount] A governance token account owned by the voter.
+/// 4. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 5. `[writable]` escrow_vault: [TokenAccount] The treasury's governance escrow.
+/// 6. `[writable]` voter_escrow: [VoterEscrow] 
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - name: [String] 
+/// - amount: [u64] 
+pub fn handler(
+	ctx: Context<WithdrawGovernanceTokens>,
+	name: String,
+	amount: u64,
+) -> Result<()> {
+	ctx.accounts.treasury.require_governance_mint(&ctx.accounts.governance_mint.key())?;
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+	// Locked while any proposal the voter has voted on is still open.
+	ctx.accounts.voter_escrow.withdraw(amount, Clock::get()?.unix_timestamp)?;
+
+	let treasury = &mut ctx.accounts.treasury;
+	treasury.governance_deposits = treasury.governance_deposits
+		.checked_sub(amount)
+		.ok_or(TreasuryManagementError::MathOverflow)?;
+
+	let treasury_key = ctx.accounts.treasury.key();
+	let escrow_authority_bump = [ctx.bumps.escrow_authority];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"escrow_authority",
+		treasury_key.as_ref(),
+		&escrow_authority_bump,
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -30,15 +31,56 @@ use anchor_spl::{
 
 		#[account(
 			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub recipient: UncheckedAccount<'info>,
+		pub authority_role: Option<Account<'info, Role>>,
+
+		#[account(
+			mut,
+			seeds = [
+				b"sol_vault",
+				treasury.key().as_ref(),
+			],
+			bump,
+		)]
+		pub sol_vault: SystemAccount<'info>,
+
+		#[account(
+			mut,
+		)]
+		pub recipient: SystemAccount<'info>,
+
+		pub system_program: Program<'info, System>,
+	}
+
+	impl<'info> WithdrawSol<'info> {
+		pub fn cpi_system_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+			anchor_lang::system_program::transfer(
+				CpiContext::new_with_signer(self.system_program.to_account_info(),
+					anchor_lang::system_program::Transfer {
+						from: self.sol_vault.to_account_info(),
+						to: self.recipient.to_account_info(),
+					},
+					signer_seeds,
+				),
+				amount,
+			)
+		}
 	}
 
+
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` recipient: [AccountInfo] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` sol_vault: [AccountInfo] System-owned PDA holding the treasury's lamports.
+/// 4. `[writable]` recipient: [AccountInfo] 
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for the lamport transfer
 ///
 /// Data:
 /// - name: [String] 
@@ -48,7 +90,46 @@ pub fn handler(
 	name: String,
 	amount: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ExecutePayments,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+	// The vault has no data, so it may only be drained completely or kept rent exempt.
+	let vault_balance = ctx.accounts.sol_vault.lamports();
+	let remaining = vault_balance
+		.checked_sub(amount)
+		.ok_or(TreasuryManagementError::InsufficientFunds)?;
+	require!(
+		remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+		TreasuryManagementError::InsufficientFunds
+	);
+
+	let accounts = &mut *ctx.accounts;
+	accounts.treasury.record_spending(
+		&accounts.authority.key(),
+		accounts.authority_role.as_deref_mut(),
+		amount,
+	)?;
+
+	let treasury_key = ctx.accounts.treasury.key();
+	let vault_bump = [ctx.bumps.sol_vault];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"sol_vault",
+		treasury_key.as_ref(),
+		&vault_bump,
+	]];
+
+	ctx.accounts.cpi_system_transfer(amount, signer_seeds)?;
+
+	let treasury = &mut ctx.accounts.treasury;
+	treasury.total_withdrawn = treasury.total_withdrawn
+		.checked_add(amount)
+		.ok_or(TreasuryManagementError::MathOverflow)?;
+
 	Ok(())
 }
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 use std::str::FromStr;
 
@@ -28,35 +29,49 @@ use anchor_spl::{
 		)]
 		pub treasury: Account<'info, Treasury>,
 
+		#[account(
+			mut,
+			seeds = [
+				b"role",
+				treasury.key().as_ref(),
+				authority.key().as_ref(),
+			],
+			bump,
+		)]
+		pub authority_role: Option<Account<'info, Role>>,
+
 		pub token_mint: Account<'info, Mint>,
 
-		/// CHECK: implement manual checks if needed
+		/// CHECK: only used as the required owner of `destination`
 		pub recipient: UncheckedAccount<'info>,
 
 		#[account(
 			mut,
+			associated_token::mint = token_mint,
+			associated_token::authority = treasury,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub source: UncheckedAccount<'info>,
+		pub token_vault: Account<'info, TokenAccount>,
 
 		#[account(
 			mut,
+			token::mint = token_mint,
+			token::authority = recipient,
 		)]
-		/// CHECK: implement manual checks if needed
-		pub destination: UncheckedAccount<'info>,
+		pub destination: Account<'info, TokenAccount>,
 
 		pub csl_spl_token_v0_0_0: Program<'info, Token>,
 	}
 
 	impl<'info> WithdrawTokens<'info> {
-		pub fn cpi_csl_spl_token_transfer(&self, amount: u64) -> Result<()> {
+		pub fn cpi_csl_spl_token_transfer(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
 			anchor_spl::token::transfer(
-				CpiContext::new(self.csl_spl_token_v0_0_0.to_account_info(), 
+				CpiContext::new_with_signer(self.csl_spl_token_v0_0_0.to_account_info(), 
 					anchor_spl::token::Transfer {
-						from: self.source.to_account_info(),
+						from: self.token_vault.to_account_info(),
 						to: self.destination.to_account_info(),
-						authority: self.authority.to_account_info()
-					}
+						authority: self.treasury.to_account_info()
+					},
+					signer_seeds,
 				),
 				amount, 
 			)
@@ -67,11 +82,12 @@ use anchor_spl::{
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[]` token_mint: [Mint] 
-/// 3. `[]` recipient: [AccountInfo] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[]` token_mint: [Mint] 
+/// 4. `[]` recipient: [AccountInfo] 
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[writable]` destination: [TokenAccount] A `token_mint` account owned by `recipient`.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
@@ -81,12 +97,34 @@ pub fn handler(
 	name: String,
 	amount: u64,
 ) -> Result<()> {
-    // Implement your business logic here...
-	
-	// Cpi calls wrappers
-	ctx.accounts.cpi_csl_spl_token_transfer(
-		Default::default(),
+	ctx.accounts.treasury.require_permission(
+		&ctx.accounts.authority.key(),
+		ctx.accounts.authority_role.as_deref(),
+		Permission::ExecutePayments,
+	)?;
+	ctx.accounts.treasury.require_not_paused()?;
+
+	require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+	require!(
+		ctx.accounts.token_vault.amount >= amount,
+		TreasuryManagementError::InsufficientFunds
+	);
+
+	let accounts = &mut *ctx.accounts;
+	accounts.treasury.record_spending(
+		&accounts.authority.key(),
+		accounts.authority_role.as_deref_mut(),
+		amount,
 	)?;
 
+	let treasury_bump = [ctx.bumps.treasury];
+	let signer_seeds: &[&[&[u8]]] = &[&[
+		b"treasury",
+		name.as_bytes(),
+		&treasury_bump,
+	]];
+
+	ctx.accounts.cpi_csl_spl_token_transfer(amount, signer_seeds)?;
+
 	Ok(())
 }
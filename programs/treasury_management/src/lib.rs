@@ -1,6 +1,7 @@
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -8,6 +9,7 @@ use anchor_lang::prelude::*;
 use std::str::FromStr;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -25,15 +27,16 @@ pub mod treasury_management {
 /// Data:
 /// - name: [String] 
 /// - signers: [Vec<Pubkey>] 
-/// - threshold: [u8] 
+/// - signer_weights: [Vec<u8>] Voting weight of each signer, in the same order.
+/// - threshold: [u8] Total signer weight needed to approve a proposal.
 /// - admin_limit: [u64] 
 /// - treasurer_limit: [u64] 
 /// - contributor_limit: [u64] 
 /// - reset_period: [u64] 
 /// - auto_stake: [bool] 
 /// - stake_target_percentage: [u8] 
-	pub fn initialize_treasury(ctx: Context<InitializeTreasury>, name: String, signers: Vec<Pubkey>, threshold: u8, admin_limit: u64, treasurer_limit: u64, contributor_limit: u64, reset_period: u64, auto_stake: bool, stake_target_percentage: u8) -> Result<()> {
-		initialize_treasury::handler(ctx, name, signers, threshold, admin_limit, treasurer_limit, contributor_limit, reset_period, auto_stake, stake_target_percentage)
+	pub fn initialize_treasury(ctx: Context<InitializeTreasury>, name: String, signers: Vec<Pubkey>, signer_weights: Vec<u8>, threshold: u8, admin_limit: u64, treasurer_limit: u64, contributor_limit: u64, reset_period: u64, auto_stake: bool, stake_target_percentage: u8) -> Result<()> {
+		initialize_treasury::handler(ctx, name, signers, signer_weights, threshold, admin_limit, treasurer_limit, contributor_limit, reset_period, auto_stake, stake_target_percentage)
 	}
 
 /// Accounts:
@@ -43,25 +46,32 @@ pub mod treasury_management {
 /// Data:
 /// - name: [String] 
 /// - new_signers: [Vec<Pubkey>] 
-/// - new_threshold: [u8] 
+/// - new_signer_weights: [Vec<u8>] Voting weight of each new signer, in the same order.
+/// - new_threshold: [u8] Total signer weight needed to approve a proposal.
 /// - admin_limit: [u64] 
 /// - treasurer_limit: [u64] 
 /// - contributor_limit: [u64] 
 /// - reset_period: [u64] 
 /// - auto_stake: [bool] 
 /// - stake_target_percentage: [u8] 
-	pub fn update_treasury_config(ctx: Context<UpdateTreasuryConfig>, name: String, new_signers: Vec<Pubkey>, new_threshold: u8, admin_limit: u64, treasurer_limit: u64, contributor_limit: u64, reset_period: u64, auto_stake: bool, stake_target_percentage: u8) -> Result<()> {
-		update_treasury_config::handler(ctx, name, new_signers, new_threshold, admin_limit, treasurer_limit, contributor_limit, reset_period, auto_stake, stake_target_percentage)
+/// - voting_period: [i64] Seconds new proposals stay open for voting.
+/// - execution_grace_period: [i64] Seconds after voting closes to execute an approved proposal.
+/// - execution_delay: [i64] Seconds between approval and the earliest execution.
+/// - execution_delay_amount_step: [u64] Each full step of a proposal's amount adds another `execution_delay`; zero disables scaling.
+/// - governance_mint: [Option<Pubkey>] Mint whose escrowed holders vote on new proposals instead of the signers.
+/// - quorum_percentage: [u8] Share of the governance mint's supply that must vote.
+/// - approval_percentage: [u8] Share of the cast token weight that must vote in favour.
+	pub fn update_treasury_config(ctx: Context<UpdateTreasuryConfig>, name: String, new_signers: Vec<Pubkey>, new_signer_weights: Vec<u8>, new_threshold: u8, admin_limit: u64, treasurer_limit: u64, contributor_limit: u64, reset_period: u64, auto_stake: bool, stake_target_percentage: u8, voting_period: i64, execution_grace_period: i64, execution_delay: i64, execution_delay_amount_step: u64, governance_mint: Option<Pubkey>, quorum_percentage: u8, approval_percentage: u8) -> Result<()> {
+		update_treasury_config::handler(ctx, name, new_signers, new_signer_weights, new_threshold, admin_limit, treasurer_limit, contributor_limit, reset_period, auto_stake, stake_target_percentage, voting_period, execution_grace_period, execution_delay, execution_delay_amount_step, governance_mint, quorum_percentage, approval_percentage)
 	}
 
 /// Accounts:
 /// 0. `[signer]` depositor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
 /// 2. `[]` token_mint: [Mint] 
-/// 3. `[writable]` source: [AccountInfo] The source account.
-/// 4. `[writable]` destination: [AccountInfo] The destination account.
-/// 5. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 3. `[writable]` source: [TokenAccount] The depositor's token account.
+/// 4. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 5. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
@@ -73,6 +83,8 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[writable, signer]` depositor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[writable]` sol_vault: [AccountInfo] System-owned PDA holding the treasury's lamports.
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for the lamport transfer
 ///
 /// Data:
 /// - name: [String] 
@@ -84,11 +96,12 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[]` token_mint: [Mint] 
-/// 3. `[]` recipient: [AccountInfo] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[]` token_mint: [Mint] 
+/// 4. `[]` recipient: [AccountInfo] 
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[writable]` destination: [TokenAccount] A `token_mint` account owned by `recipient`.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
@@ -100,7 +113,10 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` recipient: [AccountInfo] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` sol_vault: [AccountInfo] System-owned PDA holding the treasury's lamports.
+/// 4. `[writable]` recipient: [AccountInfo] 
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for the lamport transfer
 ///
 /// Data:
 /// - name: [String] 
@@ -112,6 +128,9 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` role: [Role] Created on first assignment, updated afterwards.
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
@@ -130,6 +149,8 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` role: [Role] Closed, with its rent refunded to `authority`.
 ///
 /// Data:
 /// - name: [String] 
@@ -140,78 +161,184 @@ pub mod treasury_management {
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
-/// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` payment_stream: [PaymentStream] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury. The stream's full value counts against its spending limit.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
+/// - stream_id: [u64] Must equal the treasury's `stream_count`.
 /// - recipient: [Pubkey] 
 /// - token_mint: [Pubkey] 
 /// - amount_per_period: [u64] 
 /// - period_duration: [i64] 
 /// - start_time: [i64] 
 /// - end_time: [i64] 
+/// - cliff_time: [Option<i64>] Nothing is claimable before it; defaults to `start_time`.
 /// - category: [u8] 
-	pub fn create_payment_stream(ctx: Context<CreatePaymentStream>, name: String, recipient: Pubkey, token_mint: Pubkey, amount_per_period: u64, period_duration: i64, start_time: i64, end_time: i64, category: u8) -> Result<()> {
-		create_payment_stream::handler(ctx, name, recipient, token_mint, amount_per_period, period_duration, start_time, end_time, category)
+/// - transfer_requires_approval: [bool] Whether `transfer_stream_recipient` also needs treasury approval.
+	pub fn create_payment_stream(ctx: Context<CreatePaymentStream>, name: String, stream_id: u64, recipient: Pubkey, token_mint: Pubkey, amount_per_period: u64, period_duration: i64, start_time: i64, end_time: i64, cliff_time: Option<i64>, category: u8, transfer_requires_approval: bool) -> Result<()> {
+		create_payment_stream::handler(ctx, name, stream_id, recipient, token_mint, amount_per_period, period_duration, start_time, end_time, cliff_time, category, transfer_requires_approval)
 	}
 
 /// Accounts:
 /// 0. `[signer]` executor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` executor_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+	pub fn execute_stream_payment(ctx: Context<ExecuteStreamPayment>, name: String, stream_id: u64) -> Result<()> {
+		execute_stream_payment::handler(ctx, name, stream_id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` recipient: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
 /// 2. `[writable]` payment_stream: [PaymentStream] 
 /// 3. `[]` token_mint: [Mint] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 4. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 5. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
-/// - recipient: [Pubkey] 
-	pub fn execute_stream_payment(ctx: Context<ExecuteStreamPayment>, name: String, recipient: Pubkey) -> Result<()> {
-		execute_stream_payment::handler(ctx, name, recipient)
+/// - stream_id: [u64] 
+	pub fn claim_stream(ctx: Context<ClaimStream>, name: String, stream_id: u64) -> Result<()> {
+		claim_stream::handler(ctx, name, stream_id)
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` payment_stream: [PaymentStream] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] Closed, with its rent refunded to `creator`.
+/// 4. `[writable]` creator: [AccountInfo] The stream's `created_by`.
+/// 5. `[writable]` creator_role: [Role] Optional, the creator's role; the unspent budget is credited back to its spending limit within the period it was charged to.
+/// 6. `[]` token_mint: [Mint] 
+/// 7. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 8. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 9. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
-/// - recipient: [Pubkey] 
-	pub fn cancel_payment_stream(ctx: Context<CancelPaymentStream>, name: String, recipient: Pubkey) -> Result<()> {
-		cancel_payment_stream::handler(ctx, name, recipient)
+/// - stream_id: [u64] 
+	pub fn cancel_payment_stream(ctx: Context<CancelPaymentStream>, name: String, stream_id: u64) -> Result<()> {
+		cancel_payment_stream::handler(ctx, name, stream_id)
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` milestone_payment: [MilestonePayment] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+	pub fn pause_payment_stream(ctx: Context<PausePaymentStream>, name: String, stream_id: u64) -> Result<()> {
+		pause_payment_stream::handler(ctx, name, stream_id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+	pub fn resume_payment_stream(ctx: Context<ResumePaymentStream>, name: String, stream_id: u64) -> Result<()> {
+		resume_payment_stream::handler(ctx, name, stream_id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+/// - amount_per_period: [Option<u64>] New rate, applied from now on.
+/// - end_time: [Option<i64>] New end; may not be in the past or before the cliff.
+	pub fn update_payment_stream(ctx: Context<UpdatePaymentStream>, name: String, stream_id: u64, amount_per_period: Option<u64>, end_time: Option<i64>) -> Result<()> {
+		update_payment_stream::handler(ctx, name, stream_id, amount_per_period, end_time)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury. The top-up counts against its spending limit.
+/// 3. `[writable]` payment_stream: [PaymentStream] 
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+/// - amount: [u64] Added to the stream's budget.
+	pub fn top_up_payment_stream(ctx: Context<TopUpPaymentStream>, name: String, stream_id: u64, amount: u64) -> Result<()> {
+		top_up_payment_stream::handler(ctx, name, stream_id, amount)
+	}
+
+/// Accounts:
+/// 0. `[signer]` recipient: [AccountInfo] The stream's current recipient.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` payment_stream: [PaymentStream] 
+/// 3. `[signer]` approver: [AccountInfo] Optional, required when the stream's `transfer_requires_approval` is set.
+/// 4. `[]` approver_role: [Role] Optional, the approver's role on this treasury.
+///
+/// Data:
+/// - name: [String] 
+/// - stream_id: [u64] 
+/// - new_recipient: [Pubkey] 
+	pub fn transfer_stream_recipient(ctx: Context<TransferStreamRecipient>, name: String, stream_id: u64, new_recipient: Pubkey) -> Result<()> {
+		transfer_stream_recipient::handler(ctx, name, stream_id, new_recipient)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` milestone_payment: [MilestonePayment] 
+/// 4. `[]` token_mint: [Mint] The mint the milestone pays in.
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 7. `[writable]` escrow_vault: [TokenAccount] Holds the milestone's funds until it is completed or cancelled.
+/// 8. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
 /// - id: [u64] 
 /// - recipient: [Pubkey] 
-/// - token_mint: [Pubkey] 
 /// - amount: [u64] 
 /// - description: [String] type
 /// - category: [u8] 
-	pub fn create_milestone_payment(ctx: Context<CreateMilestonePayment>, name: String, id: u64, recipient: Pubkey, token_mint: Pubkey, amount: u64, description: String, category: u8) -> Result<()> {
-		create_milestone_payment::handler(ctx, name, id, recipient, token_mint, amount, description, category)
+	pub fn create_milestone_payment(ctx: Context<CreateMilestonePayment>, name: String, id: u64, recipient: Pubkey, amount: u64, description: String, category: u8) -> Result<()> {
+		create_milestone_payment::handler(ctx, name, id, recipient, amount, description, category)
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` milestone_payment: [MilestonePayment] 
-/// 3. `[]` token_mint: [Mint] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` milestone_payment: [MilestonePayment] 
+/// 4. `[writable]` creator: [AccountInfo] Whoever created the milestone; receives the escrow account's rent.
+/// 5. `[]` token_mint: [Mint] 
+/// 6. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 7. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 8. `[writable]` escrow_vault: [TokenAccount] The milestone's escrow, closed once released.
+/// 9. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
+/// 10. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
@@ -223,56 +350,152 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` recurring_payment: [RecurringPayment] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` milestone_payment: [MilestonePayment] 
+/// 4. `[writable]` creator: [AccountInfo] Whoever created the milestone; receives the rent of both closed accounts.
+/// 5. `[writable]` creator_role: [Role] Optional, the creator's role; the milestone's amount is credited back to its spending limit within the period it was charged to.
+/// 6. `[]` token_mint: [Mint] 
+/// 7. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 8. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 9. `[writable]` escrow_vault: [TokenAccount] The milestone's escrow, emptied into `token_vault` and closed.
+/// 10. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn cancel_milestone_payment(ctx: Context<CancelMilestonePayment>, name: String, id: u64) -> Result<()> {
+		cancel_milestone_payment::handler(ctx, name, id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
 /// - recipient: [Pubkey] 
 /// - token_mint: [Pubkey] 
 /// - amount: [u64] 
-/// - interval: [i64] 
+/// - interval: [i64] Seconds between installments on an `Interval` schedule; ignored otherwise.
 /// - category: [u8] 
-	pub fn create_recurring_payment(ctx: Context<CreateRecurringPayment>, name: String, recipient: Pubkey, token_mint: Pubkey, amount: u64, interval: i64, category: u8) -> Result<()> {
-		create_recurring_payment::handler(ctx, name, recipient, token_mint, amount, interval, category)
+/// - max_payments: [Option<u32>] Deactivate after this many installments.
+/// - end_date: [Option<i64>] Deactivate once the next installment would fall after this time.
+/// - schedule: [RecurringSchedule] A fixed interval or a calendar schedule.
+	pub fn create_recurring_payment(ctx: Context<CreateRecurringPayment>, name: String, recipient: Pubkey, token_mint: Pubkey, amount: u64, interval: i64, category: u8, max_payments: Option<u32>, end_date: Option<i64>, schedule: RecurringSchedule) -> Result<()> {
+		create_recurring_payment::handler(ctx, name, recipient, token_mint, amount, interval, category, max_payments, end_date, schedule)
 	}
 
 /// Accounts:
 /// 0. `[signer]` executor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` recurring_payment: [RecurringPayment] 
-/// 3. `[]` token_mint: [Mint] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 2. `[writable]` executor_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[writable]` destination: [TokenAccount] The payment's destination if set, otherwise a `token_mint` account owned by the recipient.
 /// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
 /// - name: [String] 
 /// - recipient: [Pubkey] 
-	pub fn execute_recurring_payment(ctx: Context<ExecuteRecurringPayment>, name: String, recipient: Pubkey) -> Result<()> {
-		execute_recurring_payment::handler(ctx, name, recipient)
+/// - max_intervals: [u8] Most due installments to pay in this call, 1 to `MAX_RECURRING_CATCH_UP`.
+	pub fn execute_recurring_payment(ctx: Context<ExecuteRecurringPayment>, name: String, recipient: Pubkey, max_intervals: u8) -> Result<()> {
+		execute_recurring_payment::handler(ctx, name, recipient, max_intervals)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+	pub fn pause_recurring_payment(ctx: Context<PauseRecurringPayment>, name: String, recipient: Pubkey) -> Result<()> {
+		pause_recurring_payment::handler(ctx, name, recipient)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+	pub fn resume_recurring_payment(ctx: Context<ResumeRecurringPayment>, name: String, recipient: Pubkey) -> Result<()> {
+		resume_recurring_payment::handler(ctx, name, recipient)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+	pub fn skip_recurring_payment(ctx: Context<SkipRecurringPayment>, name: String, recipient: Pubkey) -> Result<()> {
+		skip_recurring_payment::handler(ctx, name, recipient)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[]` destination: [TokenAccount] Optional, a `token_mint` account to pay future installments into.
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+/// - amount: [Option<u64>] New amount per installment, if changing it.
+/// - clear_destination: [bool] Pay future installments to any `token_mint` account owned by `recipient` again; cannot be combined with `destination`.
+	pub fn update_recurring_payment(ctx: Context<UpdateRecurringPayment>, name: String, recipient: Pubkey, amount: Option<u64>, clear_destination: bool) -> Result<()> {
+		update_recurring_payment::handler(ctx, name, recipient, amount, clear_destination)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` recurring_payment: [RecurringPayment] 
+/// 4. `[writable]` creator: [AccountInfo] Whoever created the recurring payment; receives the account's rent.
+///
+/// Data:
+/// - name: [String] 
+/// - recipient: [Pubkey] 
+	pub fn cancel_recurring_payment(ctx: Context<CancelRecurringPayment>, name: String, recipient: Pubkey) -> Result<()> {
+		cancel_recurring_payment::handler(ctx, name, recipient)
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[]` token_mint: [Mint] 
-/// 3. `[writable]` source: [AccountInfo] The source account.
-/// 4. `[writable]` destination: [AccountInfo] The destination account.
+/// 2. `[writable]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[]` token_mint: [Mint] 
+/// 4. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
 /// 5. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///    6.. `[writable]` remaining accounts: [TokenAccount] One `token_mint` account per recipient, in order.
 ///
 /// Data:
 /// - name: [String] 
 /// - recipients: [Vec<Pubkey>] 
 /// - amounts: [Vec<u64>] 
-	pub fn batch_transfer(ctx: Context<BatchTransfer>, name: String, recipients: Vec<Pubkey>, amounts: Vec<u64>) -> Result<()> {
+	pub fn batch_transfer<'info>(ctx: Context<'_, '_, 'info, 'info, BatchTransfer<'info>>, name: String, recipients: Vec<Pubkey>, amounts: Vec<u64>) -> Result<()> {
 		batch_transfer::handler(ctx, name, recipients, amounts)
 	}
 
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
 ///
 /// Data:
 /// - name: [String] 
@@ -284,6 +507,7 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
 ///
 /// Data:
 /// - name: [String] 
@@ -294,9 +518,10 @@ pub mod treasury_management {
 
 /// Accounts:
 /// 0. `[signer]` proposer: [AccountInfo] 
-/// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` proposal: [Proposal] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` proposer_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
@@ -313,7 +538,9 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` voter: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` proposal: [Proposal] 
+/// 2. `[]` voter_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` delegation: [VoteDelegation] Optional, when `voter` votes as a signer's delegate.
 ///
 /// Data:
 /// - name: [String] 
@@ -323,14 +550,128 @@ pub mod treasury_management {
 		vote_on_proposal::handler(ctx, name, id, vote_for)
 	}
 
+/// Accounts:
+/// 0. `[signer]` voter: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] 
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn revoke_vote(ctx: Context<RevokeVote>, name: String, id: u64) -> Result<()> {
+		revoke_vote::handler(ctx, name, id)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` delegator: [AccountInfo] A treasury signer.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` delegation: [VoteDelegation] Created on first use; delegating again replaces it.
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - delegate: [Pubkey] Allowed to vote on the delegator's behalf.
+/// - expires_at: [Option<i64>] Timestamp after which the delegation lapses.
+	pub fn delegate_vote(ctx: Context<DelegateVote>, name: String, delegate: Pubkey, expires_at: Option<i64>) -> Result<()> {
+		delegate_vote::handler(ctx, name, delegate, expires_at)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` delegator: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` delegation: [VoteDelegation] Closed, with its rent refunded to `delegator`.
+///
+/// Data:
+/// - name: [String] 
+	pub fn revoke_delegation(ctx: Context<RevokeDelegation>, name: String) -> Result<()> {
+		revoke_delegation::handler(ctx, name)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` voter: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` governance_mint: [Mint] The treasury's governance mint.
+/// 3. `[writable]` source: [TokenAccount] The voter's governance token account.
+/// 4. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 5. `[writable]` escrow_vault: [TokenAccount] Holds every voter's escrowed tokens of this treasury's current governance mint.
+/// 6. `[writable]` voter_escrow: [VoterEscrow] The voter's escrowed balance, created on first deposit.
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - amount: [u64] 
+	pub fn deposit_governance_tokens(ctx: Context<DepositGovernanceTokens>, name: String, amount: u64) -> Result<()> {
+		deposit_governance_tokens::handler(ctx, name, amount)
+	}
+
+/// Accounts:
+/// 0. `[signer]` voter: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` governance_mint: [Mint] The treasury's governance mint.
+/// 3. `[writable]` destination: [TokenAccount] A governance token account owned by the voter.
+/// 4. `[]` escrow_authority: [AccountInfo] Owns the treasury's escrow vaults; never signs proposal instructions.
+/// 5. `[writable]` escrow_vault: [TokenAccount] The treasury's governance escrow.
+/// 6. `[writable]` voter_escrow: [VoterEscrow] 
+/// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
+///
+/// Data:
+/// - name: [String] 
+/// - amount: [u64] 
+	pub fn withdraw_governance_tokens(ctx: Context<WithdrawGovernanceTokens>, name: String, amount: u64) -> Result<()> {
+		withdraw_governance_tokens::handler(ctx, name, amount)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` voter: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] A token-weighted proposal.
+/// 3. `[writable]` voter_escrow: [VoterEscrow] The voter's escrowed governance tokens, used as vote weight.
+/// 4. `[writable]` vote_record: [VoteRecord] The voter's vote on this proposal, created on first vote.
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+/// - vote_for: [bool] 
+	pub fn cast_token_vote(ctx: Context<CastTokenVote>, name: String, id: u64, vote_for: bool) -> Result<()> {
+		cast_token_vote::handler(ctx, name, id, vote_for)
+	}
+
+/// Accounts:
+/// 0. `[writable, signer]` voter: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] A token-weighted proposal.
+/// 3. `[writable]` vote_record: [VoteRecord] Closed, with its rent refunded to `voter`.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn revoke_token_vote(ctx: Context<RevokeTokenVote>, name: String, id: u64) -> Result<()> {
+		revoke_token_vote::handler(ctx, name, id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` caller: [AccountInfo] Anyone may finalize once voting has closed.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] A token-weighted proposal.
+/// 3. `[]` governance_mint: [Mint] The proposal's governance mint, whose supply sets the quorum.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn finalize_proposal(ctx: Context<FinalizeProposal>, name: String, id: u64) -> Result<()> {
+		finalize_proposal::handler(ctx, name, id)
+	}
+
 /// Accounts:
 /// 0. `[signer]` executor: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
-/// 2. `[writable]` proposal: [Proposal] 
-/// 3. `[]` token_mint: [Mint] 
-/// 4. `[writable]` source: [AccountInfo] The source account.
-/// 5. `[writable]` destination: [AccountInfo] The destination account.
-/// 6. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 2. `[]` executor_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` token_mint: [Mint] 
+/// 5. `[writable]` token_vault: [TokenAccount] The treasury's associated token account for `token_mint`.
+/// 6. `[writable]` destination: [TokenAccount] A `token_mint` account owned by the recipient.
 /// 7. `[]` csl_spl_token_v0_0_0: [AccountInfo] Auto-generated, CslSplTokenProgram v0.0.0
 ///
 /// Data:
@@ -340,9 +681,97 @@ pub mod treasury_management {
 		execute_proposal::handler(ctx, name, id)
 	}
 
+/// Accounts:
+/// 0. `[signer]` proposer: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` proposer_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] Sized to fit `instructions`.
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+/// - title: [String] 
+/// - description: [String] 
+/// - instructions: [Vec<ProposalInstruction>] Invoked in order with the treasury PDA as signer once approved.
+	pub fn create_instruction_proposal(ctx: Context<CreateInstructionProposal>, name: String, id: u64, title: String, description: String, instructions: Vec<ProposalInstruction>) -> Result<()> {
+		create_instruction_proposal::handler(ctx, name, id, title, description, instructions)
+	}
+
+/// Accounts:
+/// 0. `[signer]` executor: [AccountInfo] 
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[]` executor_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+///    4..n. Remaining accounts: every program and account referenced by the proposal's instructions, other than the treasury itself.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn execute_instruction_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteInstructionProposal<'info>>, name: String, id: u64) -> Result<()> {
+		execute_instruction_proposal::handler(ctx, name, id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` proposer: [AccountInfo] 
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` proposer_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` proposal: [Proposal] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+/// - title: [String] 
+/// - description: [String] 
+/// - config: [TreasuryConfig] The complete settings to apply once approved.
+	pub fn create_config_proposal(ctx: Context<CreateConfigProposal>, name: String, id: u64, title: String, description: String, config: TreasuryConfig) -> Result<()> {
+		create_config_proposal::handler(ctx, name, id, title, description, config)
+	}
+
+/// Accounts:
+/// 0. `[signer]` executor: [AccountInfo] Any current treasury signer.
+/// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] 
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn execute_config_proposal(ctx: Context<ExecuteConfigProposal>, name: String, id: u64) -> Result<()> {
+		execute_config_proposal::handler(ctx, name, id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` caller: [AccountInfo] Anyone may expire a stale proposal.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] Closed, with its rent refunded to `proposer`.
+/// 3. `[writable]` proposer: [AccountInfo] The proposal's original proposer.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn expire_proposal(ctx: Context<ExpireProposal>, name: String, id: u64) -> Result<()> {
+		expire_proposal::handler(ctx, name, id)
+	}
+
+/// Accounts:
+/// 0. `[signer]` authority: [AccountInfo] The proposer, or one of the cancelling signers.
+/// 1. `[]` treasury: [Treasury] 
+/// 2. `[writable]` proposal: [Proposal] Closed, with its rent refunded to `proposer`.
+/// 3. `[writable]` proposer: [AccountInfo] The proposal's original proposer.
+///    4..n. `[signer]` Remaining accounts: further treasury signers co-signing the cancellation.
+///
+/// Data:
+/// - name: [String] 
+/// - id: [u64] 
+	pub fn cancel_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, CancelProposal<'info>>, name: String, id: u64) -> Result<()> {
+		cancel_proposal::handler(ctx, name, id)
+	}
+
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[writable]` treasury: [Treasury] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
 ///
 /// Data:
 /// - name: [String] 
@@ -363,8 +792,9 @@ pub mod treasury_management {
 /// Accounts:
 /// 0. `[signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` whitelist_entry: [WhitelistedRecipient] 
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` whitelist_entry: [WhitelistedRecipient] 
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - name: [String] 
@@ -375,9 +805,10 @@ pub mod treasury_management {
 	}
 
 /// Accounts:
-/// 0. `[signer]` authority: [AccountInfo] 
+/// 0. `[writable, signer]` authority: [AccountInfo] 
 /// 1. `[]` treasury: [Treasury] 
-/// 2. `[writable]` whitelist_entry: [WhitelistedRecipient] 
+/// 2. `[]` authority_role: [Role] Optional, the signer's role on this treasury.
+/// 3. `[writable]` whitelist_entry: [WhitelistedRecipient] 
 ///
 /// Data:
 /// - name: [String] 
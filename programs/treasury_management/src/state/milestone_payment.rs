@@ -1,6 +1,9 @@
-
+use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 
+/// A one-off payment of `amount`, held in the milestone's escrow token
+/// account from creation until it is completed or cancelled.
 #[account]
 pub struct MilestonePayment {
 	pub treasury: Pubkey,
@@ -13,4 +16,29 @@ pub struct MilestonePayment {
 	pub completed_at: i64,
 	pub created_by: Pubkey,
 	pub category: u8,
+	/// Start of the creator's spending-limit period `amount` was charged
+	/// to; a cancellation only credits it back within that period.
+	pub charge_period: i64,
+}
+
+impl MilestonePayment {
+	pub fn validate(amount: u64, description: &str) -> Result<()> {
+		require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+		require!(
+			description.len() <= MAX_MILESTONE_DESCRIPTION_LENGTH,
+			TreasuryManagementError::InvalidMilestoneDescription
+		);
+
+		Ok(())
+	}
+
+	/// Marks the milestone as done and returns the amount to release.
+	pub fn complete(&mut self, now: i64) -> Result<u64> {
+		require!(!self.is_completed, TreasuryManagementError::MilestoneAlreadyCompleted);
+
+		self.is_completed = true;
+		self.completed_at = now;
+
+		Ok(self.amount)
+	}
 }
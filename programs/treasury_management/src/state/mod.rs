@@ -8,6 +8,9 @@ pub mod milestone_payment;
 pub mod recurring_payment;
 pub mod proposal;
 pub mod whitelisted_recipient;
+pub mod voter_escrow;
+pub mod vote_record;
+pub mod vote_delegation;
 
 pub use role::*;
 pub use treasury::*;
@@ -16,3 +19,6 @@ pub use milestone_payment::*;
 pub use recurring_payment::*;
 pub use proposal::*;
 pub use whitelisted_recipient::*;
+pub use voter_escrow::*;
+pub use vote_record::*;
+pub use vote_delegation::*;
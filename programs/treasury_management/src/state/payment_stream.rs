@@ -1,18 +1,219 @@
-
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 
+/// Tokens that vest linearly, second by second, from `start_time` to
+/// `end_time` at `amount_per_period` every `period_duration` seconds.
 #[account]
 pub struct PaymentStream {
 	pub treasury: Pubkey,
+	pub id: u64,
 	pub recipient: Pubkey,
 	pub token_mint: Pubkey,
 	pub amount_per_period: u64,
 	pub period_duration: i64,
 	pub start_time: i64,
 	pub end_time: i64,
+	/// Nothing can be claimed before this time; at the cliff everything
+	/// accrued since `start_time` becomes claimable at once.
+	pub cliff_time: i64,
+	/// Amount accrued up to `accrued_until`. Accrual continues from there at
+	/// the current `amount_per_period` unless the stream is paused.
+	pub accrued: u64,
+	pub accrued_until: i64,
+	/// Total set aside for this stream; accrual stops once it is reached.
+	pub budget: u64,
+	pub is_paused: bool,
 	pub last_payment_time: i64,
 	pub total_paid: u64,
 	pub is_active: bool,
 	pub category: u8,
 	pub created_by: Pubkey,
+	/// Whether moving the stream to a new recipient wallet also needs a
+	/// co-signature from someone who can manage the treasury's streams.
+	pub transfer_requires_approval: bool,
+	/// Start of the creator's spending-limit period the budget was charged
+	/// to; the unspent budget is only credited back within that period.
+	pub charge_period: i64,
+}
+
+impl PaymentStream {
+	pub fn validate_schedule(
+		amount_per_period: u64,
+		period_duration: i64,
+		start_time: i64,
+		end_time: i64,
+		cliff_time: i64,
+	) -> Result<()> {
+		require!(amount_per_period > 0, TreasuryManagementError::InvalidPaymentAmount);
+		require!(
+			period_duration > 0
+				&& start_time < end_time
+				&& (start_time..=end_time).contains(&cliff_time),
+			TreasuryManagementError::InvalidStreamSchedule
+		);
+
+		Ok(())
+	}
+
+	/// Everything the stream pays out over its lifetime at its initial rate.
+	pub fn total_amount(&self) -> Result<u64> {
+		self.accrual_over(self.end_time.saturating_sub(self.start_time))
+	}
+
+	/// Amount accrued by `now`, whether or not the cliff has passed.
+	pub fn accrued_amount(&self, now: i64) -> Result<u64> {
+		let until = now.min(self.end_time);
+		if self.is_paused || until <= self.accrued_until {
+			return Ok(self.accrued.min(self.budget));
+		}
+
+		let accrued = self.accrued
+			.checked_add(self.accrual_over(until - self.accrued_until)?)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(accrued.min(self.budget))
+	}
+
+	/// Amount vested by `now`, including what has already been paid.
+	pub fn vested_amount(&self, now: i64) -> Result<u64> {
+		if now < self.cliff_time {
+			return Ok(0);
+		}
+
+		self.accrued_amount(now)
+	}
+
+	pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+		Ok(self.vested_amount(now)?.saturating_sub(self.total_paid))
+	}
+
+	/// Marks everything vested by `now` as paid and returns the amount to
+	/// transfer.
+	pub fn record_payout(&mut self, now: i64) -> Result<u64> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+
+		let amount = self.claimable_amount(now)?;
+		require!(amount > 0, TreasuryManagementError::PaymentStreamNotDue);
+
+		self.total_paid = self.total_paid
+			.checked_add(amount)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+		self.last_payment_time = now;
+
+		Ok(amount)
+	}
+
+	/// Ends the stream, marking what the recipient has vested but not yet
+	/// received as paid, and returns that amount. The unvested remainder
+	/// never leaves the treasury vault; see `unspent_budget`.
+	pub fn cancel(&mut self, now: i64) -> Result<u64> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+
+		let amount = self.claimable_amount(now)?;
+		self.total_paid = self.total_paid
+			.checked_add(amount)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+		self.last_payment_time = now;
+		self.is_active = false;
+
+		Ok(amount)
+	}
+
+	/// The part of `budget` that was never paid out.
+	pub fn unspent_budget(&self) -> u64 {
+		self.budget.saturating_sub(self.total_paid)
+	}
+
+	/// Points the stream, including anything vested but not yet claimed, at
+	/// a new wallet. `total_paid` carries over unchanged.
+	pub fn transfer_recipient(&mut self, new_recipient: Pubkey) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+		require!(
+			new_recipient != self.recipient && new_recipient != Pubkey::default(),
+			TreasuryManagementError::InvalidStreamRecipient
+		);
+
+		self.recipient = new_recipient;
+
+		Ok(())
+	}
+
+	/// Locks in what has accrued by `now`, so that a change to the stream
+	/// only applies from this point on.
+	pub fn settle(&mut self, now: i64) -> Result<()> {
+		self.accrued = self.accrued_amount(now)?;
+		self.accrued_until = self.accrued_until.max(now);
+
+		Ok(())
+	}
+
+	pub fn pause(&mut self, now: i64) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+		require!(!self.is_paused, TreasuryManagementError::PaymentStreamPaused);
+
+		self.settle(now)?;
+		self.is_paused = true;
+
+		Ok(())
+	}
+
+	pub fn resume(&mut self, now: i64) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+		require!(self.is_paused, TreasuryManagementError::PaymentStreamNotPaused);
+
+		self.settle(now)?;
+		self.is_paused = false;
+
+		Ok(())
+	}
+
+	/// Changes the rate and/or end of the stream from `now` on. The end can
+	/// move either way, but not into the past or before the cliff.
+	pub fn update_terms(
+		&mut self,
+		amount_per_period: Option<u64>,
+		end_time: Option<i64>,
+		now: i64,
+	) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+
+		self.settle(now)?;
+
+		if let Some(amount_per_period) = amount_per_period {
+			require!(amount_per_period > 0, TreasuryManagementError::InvalidPaymentAmount);
+			self.amount_per_period = amount_per_period;
+		}
+
+		if let Some(end_time) = end_time {
+			require!(
+				end_time >= now && end_time > self.start_time && end_time >= self.cliff_time,
+				TreasuryManagementError::InvalidStreamSchedule
+			);
+			self.end_time = end_time;
+		}
+
+		Ok(())
+	}
+
+	pub fn top_up(&mut self, amount: u64, now: i64) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::PaymentStreamInactive);
+		require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+
+		self.settle(now)?;
+		self.budget = self.budget
+			.checked_add(amount)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(())
+	}
+
+	fn accrual_over(&self, seconds: i64) -> Result<u64> {
+		let elapsed = seconds.max(0) as u128;
+		let accrued = (self.amount_per_period as u128)
+			.checked_mul(elapsed)
+			.ok_or(TreasuryManagementError::MathOverflow)?
+			/ self.period_duration as u128;
+
+		u64::try_from(accrued).map_err(|_| TreasuryManagementError::MathOverflow.into())
+	}
 }
@@ -1,6 +1,62 @@
-
+use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalStatus {
+	Pending,
+	Approved,
+	Rejected,
+	Executed,
+	Cancelled,
+	Expired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProposalKind {
+	/// Moves `amount` of `token_mint` from the treasury vault to `recipient`.
+	Transfer,
+	/// Invokes `instructions` in order with the treasury PDA as signer.
+	Instructions,
+	/// Replaces the treasury's settings with `config`.
+	ConfigChange,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProposalVote {
+	/// The signer whose voting power the vote uses.
+	pub voter: Pubkey,
+	pub vote_for: bool,
+	/// The voter's signer weight as of the latest vote on the proposal.
+	pub weight: u8,
+	/// Who submitted the vote: `voter` itself, or their delegate.
+	pub cast_by: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalAccountMeta {
+	pub pubkey: Pubkey,
+	pub is_signer: bool,
+	pub is_writable: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProposalInstruction {
+	pub program_id: Pubkey,
+	pub accounts: Vec<ProposalAccountMeta>,
+	pub data: Vec<u8>,
+}
+
+impl ProposalInstruction {
+	/// Serialized size of a list of instructions, excluding the `Vec` prefix.
+	pub fn space(instructions: &[ProposalInstruction]) -> usize {
+		instructions
+			.iter()
+			.map(|ix| 32 + 4 + ix.accounts.len() * (32 + 1 + 1) + 4 + ix.data.len())
+			.sum()
+	}
+}
+
 #[account]
 pub struct Proposal {
 	pub treasury: Pubkey,
@@ -10,10 +66,367 @@ pub struct Proposal {
 	pub amount: u64,
 	pub token_mint: Pubkey,
 	pub recipient: Pubkey,
-	pub votes_for: u32,
-	pub votes_against: u32,
-	pub status: u8,
+	/// Weight in favour: signer weight, or escrowed tokens on a
+	/// token-weighted proposal.
+	pub votes_for: u64,
+	pub votes_against: u64,
+	pub status: ProposalStatus,
 	pub created_at: i64,
+	pub voting_deadline: i64,
+	pub execution_deadline: i64,
+	pub approved_at: i64,
+	pub executable_at: i64,
 	pub executed_at: i64,
-	pub voters: Vec<Pubkey>,
+	pub votes: Vec<ProposalVote>,
+	pub kind: ProposalKind,
+	pub instructions: Vec<ProposalInstruction>,
+	pub config: Option<TreasuryConfig>,
+	/// The treasury's governance mint when the proposal was opened. Set for
+	/// token-weighted proposals, which are decided by `finalize` rather
+	/// than by the signers.
+	pub governance_mint: Option<Pubkey>,
+	/// Unique among every proposal the treasury has opened, including
+	/// earlier proposals that used the same id.
+	pub nonce: u64,
+}
+
+impl Proposal {
+	/// Starts a fresh Pending proposal whose voting and execution deadlines
+	/// come from the treasury's current settings. The caller fills in the
+	/// kind-specific fields.
+	pub fn open(
+		&mut self,
+		treasury: &mut Account<Treasury>,
+		proposer: Pubkey,
+		title: String,
+		description: String,
+		now: i64,
+	) -> Result<()> {
+		let voting_deadline = now
+			.checked_add(treasury.voting_period)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+		let execution_deadline = voting_deadline
+			.checked_add(treasury.execution_grace_period)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		self.treasury = treasury.key();
+		self.proposer = proposer;
+		self.title = title;
+		self.description = description;
+		self.votes_for = 0;
+		self.votes_against = 0;
+		self.status = ProposalStatus::Pending;
+		self.created_at = now;
+		self.voting_deadline = voting_deadline;
+		self.execution_deadline = execution_deadline;
+		self.approved_at = 0;
+		self.executable_at = 0;
+		self.executed_at = 0;
+		self.votes = Vec::new();
+		self.governance_mint = treasury.governance_mint;
+		self.nonce = treasury.next_proposal_nonce()?;
+
+		Ok(())
+	}
+
+	pub fn validate_instructions(instructions: &[ProposalInstruction]) -> Result<()> {
+		require!(
+			!instructions.is_empty()
+				&& instructions.len() <= MAX_PROPOSAL_INSTRUCTIONS
+				&& instructions.iter().all(|ix| ix.program_id != crate::ID),
+			TreasuryManagementError::InvalidProposalInstructions
+		);
+
+		Ok(())
+	}
+
+	pub fn require_kind(&self, kind: ProposalKind) -> Result<()> {
+		require!(
+			self.kind == kind,
+			TreasuryManagementError::ProposalKindMismatch
+		);
+
+		Ok(())
+	}
+
+	pub fn validate_text(title: &str, description: &str) -> Result<()> {
+		require!(
+			!title.is_empty()
+				&& title.len() <= MAX_PROPOSAL_TITLE_LENGTH
+				&& description.len() <= MAX_PROPOSAL_DESCRIPTION_LENGTH,
+			TreasuryManagementError::InvalidProposalText
+		);
+
+		Ok(())
+	}
+
+	/// Records one signer's vote with their current weight, or switches the
+	/// direction of a vote they already cast, then settles the status.
+	/// `cast_by` differs from `voter` when a delegate votes on their behalf.
+	pub fn record_vote(
+		&mut self,
+		voter: Pubkey,
+		cast_by: Pubkey,
+		vote_for: bool,
+		treasury: &Treasury,
+		now: i64,
+	) -> Result<()> {
+		self.require_open_for_voting(now)?;
+		self.require_signer_governance()?;
+		self.retally(treasury);
+
+		let weight = treasury.signer_weight(&voter);
+		match self.votes.iter().position(|vote| vote.voter == voter) {
+			Some(index) => {
+				let previous = self.votes[index];
+				require!(
+					previous.vote_for != vote_for,
+					TreasuryManagementError::UserAlreadyVoted
+				);
+				self.remove_tally(previous.vote_for, previous.weight as u64)?;
+				self.votes[index] = ProposalVote { voter, vote_for, weight, cast_by };
+			}
+			None => self.votes.push(ProposalVote { voter, vote_for, weight, cast_by }),
+		}
+		self.add_tally(vote_for, weight as u64)?;
+
+		self.settle(treasury, now)
+	}
+
+	/// Withdraws a vote cast earlier while the proposal is still open.
+	pub fn revoke_vote(&mut self, voter: Pubkey, treasury: &Treasury, now: i64) -> Result<()> {
+		self.require_open_for_voting(now)?;
+		self.require_signer_governance()?;
+		self.retally(treasury);
+
+		let index = self.votes
+			.iter()
+			.position(|vote| vote.voter == voter)
+			.ok_or(TreasuryManagementError::VoteNotFound)?;
+		let vote = self.votes.remove(index);
+		self.remove_tally(vote.vote_for, vote.weight as u64)?;
+
+		self.settle(treasury, now)
+	}
+
+	/// Records a token holder's vote with their escrowed balance as weight,
+	/// or switches the direction of a vote they already cast. Token-weighted
+	/// proposals are only settled by `finalize` once voting closes.
+	pub fn record_token_vote(
+		&mut self,
+		record: &mut VoteRecord,
+		vote_for: bool,
+		weight: u64,
+		treasury: &Treasury,
+		now: i64,
+	) -> Result<()> {
+		self.require_open_for_voting(now)?;
+		require!(
+			self.governance_mint.is_some() && self.governance_mint == treasury.governance_mint,
+			TreasuryManagementError::GovernanceModeMismatch
+		);
+		require!(weight > 0, TreasuryManagementError::NoVotingWeight);
+
+		let previous_weight = record.weight_on(self);
+		if previous_weight > 0 {
+			require!(
+				record.vote_for != vote_for,
+				TreasuryManagementError::UserAlreadyVoted
+			);
+			self.remove_tally(record.vote_for, previous_weight)?;
+		}
+		self.add_tally(vote_for, weight)?;
+
+		record.vote_for = vote_for;
+		record.weight = weight;
+		record.proposal_nonce = self.nonce;
+
+		Ok(())
+	}
+
+	pub fn revoke_token_vote(&mut self, record: &VoteRecord, now: i64) -> Result<()> {
+		self.require_open_for_voting(now)?;
+		let weight = record.weight_on(self);
+		require!(weight > 0, TreasuryManagementError::VoteNotFound);
+
+		self.remove_tally(record.vote_for, weight)
+	}
+
+	/// Decides a token-weighted proposal once voting has closed. It passes
+	/// when the cast weight meets the treasury's quorum of
+	/// `governance_supply` and the share in favour meets its approval
+	/// percentage.
+	pub fn finalize(&mut self, treasury: &Treasury, governance_supply: u64, now: i64) -> Result<()> {
+		require!(
+			self.status == ProposalStatus::Pending,
+			TreasuryManagementError::ProposalNotPending
+		);
+		require!(
+			self.governance_mint.is_some(),
+			TreasuryManagementError::GovernanceModeMismatch
+		);
+		require!(
+			now > self.voting_deadline,
+			TreasuryManagementError::ProposalVotingOpen
+		);
+
+		let cast = self.votes_for as u128 + self.votes_against as u128;
+		let quorum_met = cast * 100 >= governance_supply as u128 * treasury.quorum_percentage as u128;
+		let approved = cast > 0
+			&& self.votes_for as u128 * 100 >= cast * treasury.approval_percentage as u128;
+
+		if quorum_met && approved {
+			self.approve(treasury, now)
+		} else {
+			self.status = ProposalStatus::Rejected;
+			Ok(())
+		}
+	}
+
+	fn require_signer_governance(&self) -> Result<()> {
+		require!(
+			self.governance_mint.is_none(),
+			TreasuryManagementError::GovernanceModeMismatch
+		);
+
+		Ok(())
+	}
+
+	fn require_open_for_voting(&self, now: i64) -> Result<()> {
+		require!(
+			self.status == ProposalStatus::Pending,
+			TreasuryManagementError::ProposalNotPending
+		);
+		require!(
+			now <= self.voting_deadline,
+			TreasuryManagementError::ProposalVotingClosed
+		);
+
+		Ok(())
+	}
+
+	/// Drops the votes of anyone who is no longer a treasury signer and
+	/// re-weights the rest, so a config change to the signers also applies
+	/// to proposals that are already open. This keeps `votes` within the
+	/// `MAX_SIGNERS` entries the account has room for.
+	fn retally(&mut self, treasury: &Treasury) {
+		self.votes.retain(|vote| treasury.signers.contains(&vote.voter));
+		self.votes_for = 0;
+		self.votes_against = 0;
+		for vote in self.votes.iter_mut() {
+			vote.weight = treasury.signer_weight(&vote.voter);
+			if vote.vote_for {
+				self.votes_for += vote.weight as u64;
+			} else {
+				self.votes_against += vote.weight as u64;
+			}
+		}
+	}
+
+	fn add_tally(&mut self, vote_for: bool, weight: u64) -> Result<()> {
+		let tally = if vote_for { &mut self.votes_for } else { &mut self.votes_against };
+		*tally = tally
+			.checked_add(weight)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(())
+	}
+
+	fn remove_tally(&mut self, vote_for: bool, weight: u64) -> Result<()> {
+		let tally = if vote_for { &mut self.votes_for } else { &mut self.votes_against };
+		*tally = tally
+			.checked_sub(weight)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(())
+	}
+
+	/// Settles a Pending proposal once the outcome is decided: approved when
+	/// the weight in `votes_for` reaches `threshold`, rejected once too
+	/// little signer weight is left for that to happen.
+	fn settle(&mut self, treasury: &Treasury, now: i64) -> Result<()> {
+		let threshold = treasury.threshold as u64;
+		let remaining_for = (treasury.total_signer_weight() as u64).saturating_sub(self.votes_against);
+		if self.votes_for >= threshold {
+			self.approve(treasury, now)?;
+		} else if remaining_for < threshold {
+			self.status = ProposalStatus::Rejected;
+		}
+
+		Ok(())
+	}
+
+	/// Timelock between approval and execution. Instruction proposals can
+	/// move anything the treasury owns, which their zero `amount` does not
+	/// reflect, so they always wait the longest delay.
+	pub fn execution_delay(&self, treasury: &Treasury) -> i64 {
+		match self.kind {
+			ProposalKind::Instructions => MAX_EXECUTION_DELAY,
+			ProposalKind::Transfer | ProposalKind::ConfigChange => treasury.execution_delay_for(self.amount),
+		}
+	}
+
+	/// Approval starts the proposal's execution timelock. The execution
+	/// deadline is pushed back if needed so the full grace period still
+	/// follows the end of the timelock.
+	fn approve(&mut self, treasury: &Treasury, now: i64) -> Result<()> {
+		self.status = ProposalStatus::Approved;
+		self.approved_at = now;
+		self.executable_at = now
+			.checked_add(self.execution_delay(treasury))
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+		let earliest_deadline = self.executable_at
+			.checked_add(treasury.execution_grace_period)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+		self.execution_deadline = self.execution_deadline.max(earliest_deadline);
+
+		Ok(())
+	}
+
+	/// Besides the timelock and deadline, a signer-governed approval must
+	/// still hold under the treasury's current signers and threshold.
+	pub fn require_executable(&self, treasury: &Treasury, now: i64) -> Result<()> {
+		match self.status {
+			ProposalStatus::Approved if now > self.execution_deadline => {
+				err!(TreasuryManagementError::ProposalExpired)
+			}
+			ProposalStatus::Approved if now < self.executable_at => {
+				err!(TreasuryManagementError::ProposalTimelocked)
+			}
+			ProposalStatus::Approved => self.require_approval_holds(treasury),
+			ProposalStatus::Executed => err!(TreasuryManagementError::ProposalAlreadyExecuted),
+			_ => err!(TreasuryManagementError::ProposalNotApproved),
+		}
+	}
+
+	fn require_approval_holds(&self, treasury: &Treasury) -> Result<()> {
+		if self.governance_mint.is_some() {
+			return Ok(());
+		}
+
+		let votes_for = self.votes
+			.iter()
+			.filter(|vote| vote.vote_for)
+			.map(|vote| vote.voter);
+		require!(
+			treasury.weight_of_signers(votes_for) >= treasury.threshold as u32,
+			TreasuryManagementError::ProposalApprovalLapsed
+		);
+
+		Ok(())
+	}
+
+	/// A proposal is stale once it can no longer make progress: voting
+	/// closed without a decision, it was rejected and voting has closed, or
+	/// it was approved but never executed within the grace period.
+	/// Token-weighted proposals are decided by `finalize` when voting
+	/// closes, so they are never stale while still Pending.
+	pub fn is_stale(&self, now: i64) -> bool {
+		match self.status {
+			ProposalStatus::Pending => self.governance_mint.is_none() && now > self.voting_deadline,
+			ProposalStatus::Rejected => now > self.voting_deadline,
+			ProposalStatus::Approved => now > self.execution_deadline,
+			_ => false,
+		}
+	}
 }
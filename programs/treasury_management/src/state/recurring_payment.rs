@@ -1,6 +1,184 @@
-
+use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// When installments fall due. Calendar schedules pay at 00:00 UTC; a day
+/// past the end of a shorter month falls on that month's last day.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecurringSchedule {
+	/// Every `interval` seconds.
+	Interval,
+	/// Every month on `day`.
+	Monthly { day: u8 },
+	/// On the last Monday to Friday of every month.
+	LastBusinessDay,
+	/// On `day` of `month` and of every third month from it.
+	Quarterly { day: u8, month: u8 },
+	/// Every year on `day` of `month`.
+	Yearly { day: u8, month: u8 },
+}
+
+impl RecurringSchedule {
+	pub const SPACE: usize = 1 + 1 + 1;
+
+	pub fn validate(&self, interval: i64) -> Result<()> {
+		let valid = match *self {
+			RecurringSchedule::Interval => interval > 0,
+			RecurringSchedule::LastBusinessDay => true,
+			RecurringSchedule::Monthly { day } => (1..=31).contains(&day),
+			RecurringSchedule::Quarterly { day, month }
+			| RecurringSchedule::Yearly { day, month } => {
+				(1..=31).contains(&day) && (1..=12).contains(&month)
+			}
+		};
+		require!(valid, TreasuryManagementError::InvalidRecurringSchedule);
+
+		Ok(())
+	}
+
+	/// The first due date strictly after `now`.
+	pub fn first_payment_date(&self, now: i64, interval: i64) -> Result<i64> {
+		if *self == RecurringSchedule::Interval {
+			return now
+				.checked_add(interval)
+				.ok_or(TreasuryManagementError::MathOverflow.into());
+		}
+
+		// The first month on the schedule's cadence from this one on.
+		let (months, anchor) = self.cadence();
+		let (year, month, _) = civil_from_days(now.div_euclid(SECONDS_PER_DAY));
+		let (year, month) = add_months(year, month, (anchor as i64 - month as i64).rem_euclid(months));
+		let due = self.due_date_in(year, month)?;
+		if due > now {
+			return Ok(due);
+		}
+
+		let (year, month) = add_months(year, month, months);
+		self.due_date_in(year, month)
+	}
+
+	/// The due date after `current`, which must itself be a due date.
+	pub fn following(&self, current: i64, interval: i64) -> Result<i64> {
+		if *self == RecurringSchedule::Interval {
+			return current
+				.checked_add(interval)
+				.ok_or(TreasuryManagementError::MathOverflow.into());
+		}
+
+		let (months, _) = self.cadence();
+		let (year, month, _) = civil_from_days(current.div_euclid(SECONDS_PER_DAY));
+		let (year, month) = add_months(year, month, months);
+		self.due_date_in(year, month)
+	}
+
+	/// The first due date at or after `at`, keeping to the schedule that
+	/// `current` is on.
+	pub fn next_on_or_after(&self, current: i64, at: i64, interval: i64) -> Result<i64> {
+		if current >= at {
+			return Ok(current);
+		}
+
+		if *self == RecurringSchedule::Interval {
+			let behind = at
+				.checked_sub(current)
+				.ok_or(TreasuryManagementError::MathOverflow)?;
+			let intervals = (behind - 1) / interval + 1;
+			return intervals
+				.checked_mul(interval)
+				.and_then(|offset| current.checked_add(offset))
+				.ok_or(TreasuryManagementError::MathOverflow.into());
+		}
+
+		let mut next = current;
+		while next < at {
+			next = self.following(next, interval)?;
+		}
+
+		Ok(next)
+	}
+
+	/// Months between due dates of a calendar schedule, and a month that
+	/// has one.
+	fn cadence(&self) -> (i64, u32) {
+		match *self {
+			RecurringSchedule::Interval
+			| RecurringSchedule::Monthly { .. }
+			| RecurringSchedule::LastBusinessDay => (1, 1),
+			RecurringSchedule::Quarterly { month, .. } => (3, month as u32),
+			RecurringSchedule::Yearly { month, .. } => (12, month as u32),
+		}
+	}
+
+	fn due_date_in(&self, year: i64, month: u32) -> Result<i64> {
+		let last_day = days_in_month(year, month);
+		let day = match *self {
+			RecurringSchedule::Interval => return err!(TreasuryManagementError::InvalidRecurringSchedule),
+			RecurringSchedule::Monthly { day }
+			| RecurringSchedule::Quarterly { day, .. }
+			| RecurringSchedule::Yearly { day, .. } => (day as u32).min(last_day),
+			RecurringSchedule::LastBusinessDay => {
+				// 1970-01-01 was a Thursday; 0 is Sunday and 6 Saturday.
+				match (days_from_civil(year, month, last_day) + 4).rem_euclid(7) {
+					0 => last_day - 2,
+					6 => last_day - 1,
+					_ => last_day,
+				}
+			}
+		};
+
+		days_from_civil(year, month, day)
+			.checked_mul(SECONDS_PER_DAY)
+			.ok_or(TreasuryManagementError::MathOverflow.into())
+	}
+}
+
+fn is_leap_year(year: i64) -> bool {
+	year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+	match month {
+		4 | 6 | 9 | 11 => 30,
+		2 if is_leap_year(year) => 29,
+		2 => 28,
+		_ => 31,
+	}
+}
+
+fn add_months(year: i64, month: u32, months: i64) -> (i64, u32) {
+	let index = year * 12 + (month as i64 - 1) + months;
+	(index.div_euclid(12), index.rem_euclid(12) as u32 + 1)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+	era * 146_097 + day_of_era - 719_468
+}
+
+/// The (year, month, day) that falls `days` days after 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+	let days = days + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days - era * 146_097;
+	let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let shifted_month = (5 * day_of_year + 2) / 153;
+	let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+	let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+
+	(year_of_era + era * 400 + (month <= 2) as i64, month, day)
+}
+
+/// Pays `amount` on `schedule`, starting with its first due date after
+/// creation, until `max_payments` have been made or `end_date` has passed.
 #[account]
 pub struct RecurringPayment {
 	pub treasury: Pubkey,
@@ -12,4 +190,134 @@ pub struct RecurringPayment {
 	pub is_active: bool,
 	pub created_by: Pubkey,
 	pub category: u8,
+	pub payments_made: u32,
+	pub max_payments: Option<u32>,
+	/// No installment falls due after this time.
+	pub end_date: Option<i64>,
+	pub schedule: RecurringSchedule,
+	/// No installments fall due while paused; those missed are skipped on
+	/// resume rather than caught up.
+	pub is_paused: bool,
+	/// Token account to pay into instead of any `token_mint` account owned
+	/// by `recipient`.
+	pub destination: Option<Pubkey>,
+}
+
+impl RecurringPayment {
+	pub fn validate_schedule(
+		amount: u64,
+		max_payments: Option<u32>,
+		end_date: Option<i64>,
+		first_payment_date: i64,
+	) -> Result<()> {
+		require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+		require!(
+			max_payments.is_none_or(|max| max > 0)
+				&& end_date.is_none_or(|end| end >= first_payment_date),
+			TreasuryManagementError::InvalidRecurringSchedule
+		);
+
+		Ok(())
+	}
+
+	/// Whether `destination`, owned by `owner`, may receive installments.
+	pub fn pays_into(&self, destination: &Pubkey, owner: &Pubkey) -> bool {
+		match self.destination {
+			Some(expected) => *destination == expected,
+			None => *owner == self.recipient,
+		}
+	}
+
+	/// Whether every installment the schedule allows has been paid.
+	pub fn is_finished(&self) -> bool {
+		self.max_payments.is_some_and(|max| self.payments_made >= max)
+			|| self.end_date.is_some_and(|end| self.next_payment_date > end)
+	}
+
+	/// Records up to `max_intervals` installments that are due by `now`,
+	/// advancing the schedule past each, and returns the total to transfer.
+	/// The schedule deactivates itself once it is finished.
+	pub fn record_payments(&mut self, max_intervals: u8, now: i64) -> Result<u64> {
+		require!(self.is_active, TreasuryManagementError::RecurringPaymentInactive);
+		require!(!self.is_paused, TreasuryManagementError::RecurringPaymentPaused);
+		require!(
+			(1..=MAX_RECURRING_CATCH_UP).contains(&max_intervals),
+			TreasuryManagementError::InvalidRecurringCatchUp
+		);
+
+		let mut installments: u64 = 0;
+		while installments < max_intervals as u64
+			&& !self.is_finished()
+			&& self.next_payment_date <= now
+		{
+			installments += 1;
+			self.payments_made = self.payments_made
+				.checked_add(1)
+				.ok_or(TreasuryManagementError::MathOverflow)?;
+			self.next_payment_date = self.schedule.following(self.next_payment_date, self.interval)?;
+		}
+		require!(installments > 0, TreasuryManagementError::RecurringPaymentNotDue);
+
+		self.finish_if_done();
+
+		self.amount
+			.checked_mul(installments)
+			.ok_or(TreasuryManagementError::MathOverflow.into())
+	}
+
+	pub fn pause(&mut self) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::RecurringPaymentInactive);
+		require!(!self.is_paused, TreasuryManagementError::RecurringPaymentPaused);
+
+		self.is_paused = true;
+
+		Ok(())
+	}
+
+	/// Picks the schedule back up from `now`, skipping every installment
+	/// that fell due while paused.
+	pub fn resume(&mut self, now: i64) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::RecurringPaymentInactive);
+		require!(self.is_paused, TreasuryManagementError::RecurringPaymentNotPaused);
+
+		self.next_payment_date = self.schedule.next_on_or_after(self.next_payment_date, now, self.interval)?;
+		self.is_paused = false;
+		self.finish_if_done();
+
+		Ok(())
+	}
+
+	/// Drops the next installment without paying it. A skipped installment
+	/// does not count towards `max_payments`.
+	pub fn skip_next(&mut self) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::RecurringPaymentInactive);
+
+		self.next_payment_date = self.schedule.following(self.next_payment_date, self.interval)?;
+		self.finish_if_done();
+
+		Ok(())
+	}
+
+	/// Changes whichever terms are given. A `destination` of `Some(None)`
+	/// goes back to paying any `token_mint` account owned by `recipient`.
+	pub fn update_terms(&mut self, amount: Option<u64>, destination: Option<Option<Pubkey>>) -> Result<()> {
+		require!(self.is_active, TreasuryManagementError::RecurringPaymentInactive);
+
+		if let Some(amount) = amount {
+			require!(amount > 0, TreasuryManagementError::InvalidPaymentAmount);
+			self.amount = amount;
+		}
+
+		if let Some(destination) = destination {
+			self.destination = destination;
+		}
+
+		Ok(())
+	}
+
+	fn finish_if_done(&mut self) {
+		if self.is_finished() {
+			self.is_active = false;
+		}
+	}
 }
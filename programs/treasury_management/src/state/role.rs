@@ -1,8 +1,24 @@
-
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 
+pub const ROLE_TYPE_ADMIN: u8 = 1;
+pub const ROLE_TYPE_TREASURER: u8 = 2;
+pub const ROLE_TYPE_CONTRIBUTOR: u8 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+	ExecutePayments,
+	CreateStreams,
+	ManageRoles,
+	ViewTreasury,
+	Propose,
+	Vote,
+}
+
 #[account]
 pub struct Role {
+	pub treasury: Pubkey,
+	pub user: Pubkey,
 	pub role_type: u8,
 	pub can_execute_payments: bool,
 	pub can_create_streams: bool,
@@ -13,3 +29,56 @@ pub struct Role {
 	pub spending_limit_used: u64,
 	pub last_limit_reset: i64,
 }
+
+impl Role {
+	pub fn is_valid_role_type(role_type: u8) -> bool {
+		matches!(role_type, ROLE_TYPE_ADMIN | ROLE_TYPE_TREASURER | ROLE_TYPE_CONTRIBUTOR)
+	}
+
+	pub fn has_permission(&self, permission: Permission) -> bool {
+		match permission {
+			Permission::ExecutePayments => self.can_execute_payments,
+			Permission::CreateStreams => self.can_create_streams,
+			Permission::ManageRoles => self.can_manage_roles,
+			Permission::ViewTreasury => self.can_view_treasury,
+			Permission::Propose => self.can_propose,
+			Permission::Vote => self.can_vote,
+		}
+	}
+
+	/// Adds `amount` to the usage for the current period, starting a new
+	/// period first if `reset_period` seconds have passed since the last
+	/// reset. A `reset_period` of zero means usage never resets. Returns the
+	/// start of the period `amount` was charged to.
+	pub fn consume_spending_limit(
+		&mut self,
+		limit: u64,
+		reset_period: u64,
+		amount: u64,
+		now: i64,
+	) -> Result<i64> {
+		let elapsed = now.saturating_sub(self.last_limit_reset);
+		if reset_period > 0 && elapsed >= 0 && elapsed as u64 >= reset_period {
+			self.spending_limit_used = 0;
+			self.last_limit_reset = now;
+		}
+
+		let used = self.spending_limit_used
+			.checked_add(amount)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+		require!(used <= limit, TreasuryManagementError::SpendingLimitExceeded);
+
+		self.spending_limit_used = used;
+
+		Ok(self.last_limit_reset)
+	}
+
+	/// Gives back part of the usage charged to the period starting at
+	/// `period`, for spending that was charged up front but never left the
+	/// treasury. Once a new period has started that usage is already gone.
+	pub fn release_spending_limit(&mut self, amount: u64, period: i64) {
+		if self.last_limit_reset == period {
+			self.spending_limit_used = self.spending_limit_used.saturating_sub(amount);
+		}
+	}
+}
@@ -1,11 +1,52 @@
-
+use crate::*;
+use crate::error::TreasuryManagementError;
 use anchor_lang::prelude::*;
 
+/// The governance and limit settings of a treasury, as changed by
+/// `update_treasury_config` or an approved config-change proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TreasuryConfig {
+	pub signers: Vec<Pubkey>,
+	pub signer_weights: Vec<u8>,
+	pub threshold: u8,
+	pub admin_limit: u64,
+	pub treasurer_limit: u64,
+	pub contributor_limit: u64,
+	pub reset_period: u64,
+	pub auto_stake: bool,
+	pub stake_target_percentage: u8,
+	pub voting_period: i64,
+	pub execution_grace_period: i64,
+	pub execution_delay: i64,
+	pub execution_delay_amount_step: u64,
+	pub governance_mint: Option<Pubkey>,
+	pub quorum_percentage: u8,
+	pub approval_percentage: u8,
+}
+
+impl TreasuryConfig {
+	/// Serialized size with the maximum number of signers.
+	pub const SPACE: usize = 4 + 32 * MAX_SIGNERS + 4 + MAX_SIGNERS + 1 + 8 * 4 + 1 + 1 + 8 * 4 + 33 + 1 + 1;
+
+	pub fn validate(&self) -> Result<()> {
+		Treasury::validate_signers(&self.signers, &self.signer_weights, self.threshold)?;
+		Treasury::validate_stake_target_percentage(self.stake_target_percentage)?;
+		Treasury::validate_governance_periods(self.voting_period, self.execution_grace_period)?;
+		Treasury::validate_execution_delay(self.execution_delay)?;
+		Treasury::validate_token_governance(self.quorum_percentage, self.approval_percentage)?;
+
+		Ok(())
+	}
+}
+
 #[account]
 pub struct Treasury {
 	pub name: String,
 	pub authority: Pubkey,
 	pub signers: Vec<Pubkey>,
+	/// Voting weight of each entry in `signers`, at the same index.
+	pub signer_weights: Vec<u8>,
+	/// Total signer weight needed to approve a proposal.
 	pub threshold: u8,
 	pub is_paused: bool,
 	pub total_deposited: u64,
@@ -18,4 +59,302 @@ pub struct Treasury {
 	pub auto_stake: bool,
 	pub stake_target_percentage: u8,
 	pub whitelist_enabled: bool,
+	pub voting_period: i64,
+	pub execution_grace_period: i64,
+	pub execution_delay: i64,
+	pub execution_delay_amount_step: u64,
+	/// When set, new proposals are decided by holders of this mint, weighted
+	/// by the tokens they escrow, instead of by the signers.
+	pub governance_mint: Option<Pubkey>,
+	/// Share of the governance mint's supply that must vote on a proposal.
+	pub quorum_percentage: u8,
+	/// Share of the cast token weight that must vote for a proposal.
+	pub approval_percentage: u8,
+	/// Governance tokens currently held in voter escrow.
+	pub governance_deposits: u64,
+	/// Payment streams created so far; also the id of the next one.
+	pub stream_count: u64,
+	/// Proposals opened so far; also the nonce of the next one.
+	pub proposal_count: u64,
+}
+
+impl Treasury {
+	pub fn require_authority(&self, signer: &Pubkey) -> Result<()> {
+		require_keys_eq!(
+			*signer,
+			self.authority,
+			TreasuryManagementError::InsufficientPermissions
+		);
+
+		Ok(())
+	}
+
+	/// The treasury authority acts as a super-admin; anyone else needs a
+	/// role on this treasury that grants `permission`.
+	pub fn require_permission(
+		&self,
+		signer: &Pubkey,
+		role: Option<&Role>,
+		permission: Permission,
+	) -> Result<()> {
+		if *signer == self.authority {
+			return Ok(());
+		}
+
+		match role {
+			Some(role) if role.user == *signer && role.has_permission(permission) => Ok(()),
+			_ => err!(TreasuryManagementError::InsufficientPermissions),
+		}
+	}
+
+	/// Voting weight of `signer`, or zero if they are not a treasury signer.
+	pub fn signer_weight(&self, signer: &Pubkey) -> u8 {
+		self.signers
+			.iter()
+			.position(|key| key == signer)
+			.and_then(|index| self.signer_weights.get(index).copied())
+			.unwrap_or(0)
+	}
+
+	pub fn total_signer_weight(&self) -> u32 {
+		self.signer_weights.iter().map(|weight| *weight as u32).sum()
+	}
+
+	/// Combined weight of the distinct treasury signers among `keys`.
+	pub fn weight_of_signers(&self, keys: impl Iterator<Item = Pubkey>) -> u32 {
+		let mut seen: Vec<Pubkey> = Vec::with_capacity(self.signers.len());
+		let mut weight = 0;
+		for key in keys {
+			if self.signers.contains(&key) && !seen.contains(&key) {
+				seen.push(key);
+				weight += self.signer_weight(&key) as u32;
+			}
+		}
+
+		weight
+	}
+
+	/// Direct config updates by the authority are only allowed while the
+	/// treasury has a single signer; multisig treasuries must go through a
+	/// config-change proposal.
+	pub fn require_single_signer(&self) -> Result<()> {
+		require!(
+			self.signers.len() <= 1,
+			TreasuryManagementError::ConfigChangeRequiresProposal
+		);
+
+		Ok(())
+	}
+
+	pub fn apply_config(&mut self, config: TreasuryConfig) -> Result<()> {
+		config.validate()?;
+		require!(
+			config.governance_mint == self.governance_mint || self.governance_deposits == 0,
+			TreasuryManagementError::GovernanceTokensEscrowed
+		);
+
+		self.signers = config.signers;
+		self.signer_weights = config.signer_weights;
+		self.threshold = config.threshold;
+		self.admin_limit = config.admin_limit;
+		self.treasurer_limit = config.treasurer_limit;
+		self.contributor_limit = config.contributor_limit;
+		self.reset_period = config.reset_period;
+		self.auto_stake = config.auto_stake;
+		self.stake_target_percentage = config.stake_target_percentage;
+		self.voting_period = config.voting_period;
+		self.execution_grace_period = config.execution_grace_period;
+		self.execution_delay = config.execution_delay;
+		self.execution_delay_amount_step = config.execution_delay_amount_step;
+		self.governance_mint = config.governance_mint;
+		self.quorum_percentage = config.quorum_percentage;
+		self.approval_percentage = config.approval_percentage;
+
+		Ok(())
+	}
+
+	pub fn require_signer(&self, signer: &Pubkey) -> Result<()> {
+		require!(
+			self.signers.contains(signer),
+			TreasuryManagementError::NotTreasurySigner
+		);
+
+		Ok(())
+	}
+
+	pub fn require_governance_mint(&self, mint: &Pubkey) -> Result<()> {
+		require!(
+			self.governance_mint == Some(*mint),
+			TreasuryManagementError::GovernanceNotEnabled
+		);
+
+		Ok(())
+	}
+
+	pub fn spending_limit(&self, role_type: u8) -> u64 {
+		match role_type {
+			ROLE_TYPE_ADMIN => self.admin_limit,
+			ROLE_TYPE_TREASURER => self.treasurer_limit,
+			ROLE_TYPE_CONTRIBUTOR => self.contributor_limit,
+			_ => 0,
+		}
+	}
+
+	/// Charges an outflow against the signer's rolling spending limit. The
+	/// authority is not limited; everyone else must already have passed
+	/// `require_permission` with their role. Returns the start of the limit
+	/// period the outflow was charged to, or zero for the authority.
+	pub fn record_spending(
+		&self,
+		signer: &Pubkey,
+		role: Option<&mut Role>,
+		amount: u64,
+	) -> Result<i64> {
+		if *signer == self.authority {
+			return Ok(0);
+		}
+
+		let role = role.ok_or(TreasuryManagementError::InsufficientPermissions)?;
+		role.consume_spending_limit(
+			self.spending_limit(role.role_type),
+			self.reset_period,
+			amount,
+			Clock::get()?.unix_timestamp,
+		)
+	}
+
+	/// Credits spending that was charged to `spender` in the limit period
+	/// starting at `period`, but never paid out, back to their rolling
+	/// limit. Nothing is credited without the role, for example once it has
+	/// been revoked, or once that period is over.
+	pub fn release_spending(
+		&self,
+		spender: &Pubkey,
+		role: Option<&mut Role>,
+		amount: u64,
+		period: i64,
+	) {
+		if *spender == self.authority {
+			return;
+		}
+
+		if let Some(role) = role {
+			role.release_spending_limit(amount, period);
+		}
+	}
+
+	/// Returns the id for a new payment stream and advances the counter.
+	pub fn next_stream_id(&mut self) -> Result<u64> {
+		let id = self.stream_count;
+		self.stream_count = id
+			.checked_add(1)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(id)
+	}
+
+	/// Returns the nonce for a new proposal and advances the counter.
+	pub fn next_proposal_nonce(&mut self) -> Result<u64> {
+		let nonce = self.proposal_count;
+		self.proposal_count = nonce
+			.checked_add(1)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(nonce)
+	}
+
+	pub fn require_not_paused(&self) -> Result<()> {
+		require!(!self.is_paused, TreasuryManagementError::TreasuryPaused);
+
+		Ok(())
+	}
+
+	pub fn validate_name(name: &str) -> Result<()> {
+		require!(
+			!name.is_empty() && name.len() <= MAX_TREASURY_NAME_LENGTH,
+			TreasuryManagementError::InvalidTreasuryName
+		);
+
+		Ok(())
+	}
+
+	/// Signers must be distinct, each with a non-zero weight, and the
+	/// threshold must be reachable by their combined weight.
+	pub fn validate_signers(signers: &[Pubkey], signer_weights: &[u8], threshold: u8) -> Result<()> {
+		require!(
+			!signers.is_empty() && signers.len() <= MAX_SIGNERS,
+			TreasuryManagementError::InvalidSigners
+		);
+
+		for (i, signer) in signers.iter().enumerate() {
+			require!(
+				!signers[..i].contains(signer),
+				TreasuryManagementError::InvalidSigners
+			);
+		}
+
+		require!(
+			signer_weights.len() == signers.len() && signer_weights.iter().all(|weight| *weight > 0),
+			TreasuryManagementError::InvalidSignerWeights
+		);
+
+		let total_weight: u32 = signer_weights.iter().map(|weight| *weight as u32).sum();
+		require!(
+			threshold > 0 && threshold as u32 <= total_weight,
+			TreasuryManagementError::InvalidSignatureThreshold
+		);
+
+		Ok(())
+	}
+
+	pub fn validate_governance_periods(voting_period: i64, execution_grace_period: i64) -> Result<()> {
+		require!(
+			voting_period > 0 && execution_grace_period > 0,
+			TreasuryManagementError::InvalidGovernancePeriod
+		);
+
+		Ok(())
+	}
+
+	pub fn validate_execution_delay(execution_delay: i64) -> Result<()> {
+		require!(
+			(0..=MAX_EXECUTION_DELAY).contains(&execution_delay),
+			TreasuryManagementError::InvalidExecutionDelay
+		);
+
+		Ok(())
+	}
+
+	/// Timelock for an approved proposal moving `amount`. With a non-zero
+	/// `execution_delay_amount_step`, every full step of `amount` adds
+	/// another `execution_delay`, up to `MAX_EXECUTION_DELAY`.
+	pub fn execution_delay_for(&self, amount: u64) -> i64 {
+		if self.execution_delay_amount_step == 0 {
+			return self.execution_delay;
+		}
+
+		let steps = amount / self.execution_delay_amount_step;
+		let multiplier = i64::try_from(steps).unwrap_or(i64::MAX).saturating_add(1);
+		self.execution_delay
+			.saturating_mul(multiplier)
+			.min(MAX_EXECUTION_DELAY)
+	}
+
+	pub fn validate_token_governance(quorum_percentage: u8, approval_percentage: u8) -> Result<()> {
+		require!(
+			quorum_percentage <= 100 && (1..=100).contains(&approval_percentage),
+			TreasuryManagementError::InvalidGovernancePercentage
+		);
+
+		Ok(())
+	}
+
+	pub fn validate_stake_target_percentage(stake_target_percentage: u8) -> Result<()> {
+		require!(
+			stake_target_percentage <= 100,
+			TreasuryManagementError::InvalidStakePercentage
+		);
+
+		Ok(())
+	}
 }
@@ -0,0 +1,26 @@
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+/// A signer's voting power on a treasury, handed to `delegate` so proposals
+/// keep moving while the signer is away.
+#[account]
+pub struct VoteDelegation {
+	pub treasury: Pubkey,
+	pub delegator: Pubkey,
+	pub delegate: Pubkey,
+	/// The delegation lapses after this timestamp; `None` never expires.
+	pub expires_at: Option<i64>,
+}
+
+impl VoteDelegation {
+	pub fn require_active(&self, treasury: &Pubkey, delegate: &Pubkey, now: i64) -> Result<()> {
+		require!(
+			self.treasury == *treasury
+				&& self.delegate == *delegate
+				&& self.expires_at.is_none_or(|expires_at| now <= expires_at),
+			TreasuryManagementError::DelegationNotActive
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,28 @@
+use crate::*;
+use anchor_lang::prelude::*;
+
+/// A token holder's vote on a token-weighted proposal. A zero `weight`
+/// means no vote has been cast yet.
+///
+/// Proposal PDAs are reused once a proposal is closed, so the record also
+/// keeps the `nonce` of the proposal it was cast on. A record left behind
+/// by an earlier proposal at the same address counts as empty.
+#[account]
+pub struct VoteRecord {
+	pub proposal: Pubkey,
+	pub voter: Pubkey,
+	pub vote_for: bool,
+	pub weight: u64,
+	pub proposal_nonce: u64,
+}
+
+impl VoteRecord {
+	/// Weight this record currently holds on `proposal`.
+	pub fn weight_on(&self, proposal: &Proposal) -> u64 {
+		if self.proposal_nonce == proposal.nonce {
+			self.weight
+		} else {
+			0
+		}
+	}
+}
@@ -0,0 +1,40 @@
+use crate::error::TreasuryManagementError;
+use anchor_lang::prelude::*;
+
+/// Governance tokens a holder has escrowed with a treasury. The balance is
+/// the holder's voting weight on token-weighted proposals.
+#[account]
+pub struct VoterEscrow {
+	pub treasury: Pubkey,
+	pub owner: Pubkey,
+	pub amount: u64,
+	/// Latest voting deadline among the proposals this holder voted on;
+	/// withdrawals are blocked until it has passed.
+	pub locked_until: i64,
+}
+
+impl VoterEscrow {
+	pub fn deposit(&mut self, amount: u64) -> Result<()> {
+		self.amount = self.amount
+			.checked_add(amount)
+			.ok_or(TreasuryManagementError::MathOverflow)?;
+
+		Ok(())
+	}
+
+	pub fn withdraw(&mut self, amount: u64, now: i64) -> Result<()> {
+		require!(
+			now > self.locked_until,
+			TreasuryManagementError::GovernanceTokensLocked
+		);
+		self.amount = self.amount
+			.checked_sub(amount)
+			.ok_or(TreasuryManagementError::InsufficientFunds)?;
+
+		Ok(())
+	}
+
+	pub fn lock_until(&mut self, deadline: i64) {
+		self.locked_until = self.locked_until.max(deadline);
+	}
+}
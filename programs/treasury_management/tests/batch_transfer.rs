@@ -12,6 +12,11 @@ use {
     },
 };
 
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, treasury_fixture};
+use treasury_management::error::TreasuryManagementError;
+use anchor_spl::associated_token::get_associated_token_address;
+
+
 
 #[tokio::test]
 async fn batch_transfer_ix_success() {
@@ -86,6 +91,7 @@ async fn batch_transfer_ix_success() {
     let ix = treasury_management_ix_interface::batch_transfer_ix_setup(
         &authority_keypair,
         treasury_pda,
+        None,
         token_mint_pubkey,
         source_pubkey,
         destinations,
@@ -102,4 +108,79 @@ async fn batch_transfer_ix_success() {
     // This will likely fail without a properly initialized treasury
     // In a complete test suite, you would first initialize the treasury
     assert!(result.is_err()); // Expecting error since treasury doesn't exist yet
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn batch_transfer_ix_rejects_signer_without_permission() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name = "Permissioned Treasury".to_string();
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let outsider_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let outsider_pubkey = outsider_keypair.pubkey();
+    let token_mint_pubkey = Pubkey::new_unique();
+    let recipient_pubkey = Pubkey::new_unique();
+    let destination_pubkey = Pubkey::new_unique();
+
+    // EXECUTABLE PUBKEY
+    let token_program_pubkey = anchor_spl::token::ID;
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        outsider_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+    add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+    add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+    add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::batch_transfer_ix_setup(
+        &outsider_keypair,
+        treasury_pda,
+        None,
+        token_mint_pubkey,
+        token_vault_pubkey,
+        vec![destination_pubkey],
+        token_program_pubkey,
+        &name,
+        vec![recipient_pubkey],
+        vec![1_000],
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
@@ -0,0 +1,586 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, milestone_payment_fixture, role_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Role};
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
+
+
+#[tokio::test]
+async fn cancel_milestone_payment_ix_returns_escrow_to_treasury() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 0);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	let milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, authority_pubkey);
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_milestone_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		authority_pubkey,
+		None,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let token_vault: TokenAccount = get_program_account(&mut banks_client, token_vault_pubkey).await;
+	assert_eq!(token_vault.amount, 1_000);
+	assert!(banks_client.get_account(escrow_vault_pda).await.unwrap().is_none());
+	assert!(banks_client.get_account(milestone_payment_pda).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn cancel_milestone_payment_ix_rejects_completed_milestone() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	let mut milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, authority_pubkey);
+	milestone_payment.is_completed = true;
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_milestone_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		authority_pubkey,
+		None,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::MilestoneAlreadyCompleted);
+}
+
+#[tokio::test]
+async fn cancel_milestone_payment_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	let milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, authority_pubkey);
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_milestone_payment_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		authority_pubkey,
+		None,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn cancel_milestone_payment_ix_credits_amount_to_creator_limit() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (creator_role_pda, _creator_role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			creator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, creator_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 0);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	let milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, creator_pubkey);
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+	let mut creator_role = role_fixture(treasury_pda, creator_pubkey, 2);
+	creator_role.spending_limit_used = 1_500;
+	add_program_account(&mut program_test, creator_role_pda, &creator_role, 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_milestone_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		creator_pubkey,
+		Some(creator_role_pda),
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let token_vault: TokenAccount = get_program_account(&mut banks_client, token_vault_pubkey).await;
+	assert_eq!(token_vault.amount, 1_000);
+
+	let creator_role: Role = get_program_account(&mut banks_client, creator_role_pda).await;
+	assert_eq!(creator_role.spending_limit_used, 500);
+}
+
+#[tokio::test]
+async fn cancel_milestone_payment_ix_keeps_limit_after_a_new_period_started() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let creator_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (creator_role_pda, _creator_role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			creator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, creator_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 0);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	let milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, creator_pubkey);
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+	// The milestone was charged in an earlier period; the 1_500 used since
+	// belongs to the period that started at 1_000.
+	let mut creator_role = role_fixture(treasury_pda, creator_pubkey, 2);
+	creator_role.spending_limit_used = 1_500;
+	creator_role.last_limit_reset = 1_000;
+	add_program_account(&mut program_test, creator_role_pda, &creator_role, 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_milestone_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		creator_pubkey,
+		Some(creator_role_pda),
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let token_vault: TokenAccount = get_program_account(&mut banks_client, token_vault_pubkey).await;
+	assert_eq!(token_vault.amount, 1_000);
+
+	let creator_role: Role = get_program_account(&mut banks_client, creator_role_pda).await;
+	assert_eq!(creator_role.spending_limit_used, 1_500);
+}
@@ -0,0 +1,455 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, proposal_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, ProposalStatus};
+
+
+
+#[tokio::test]
+async fn cancel_proposal_ix_proposer_withdraws_pending_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let proposer_pubkey = proposer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [authority_pubkey, signer_pubkey, proposer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, proposer_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Pending;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_proposal_ix_setup(
+		&proposer_keypair,
+		&[],
+		treasury_pda,
+		proposal_pda,
+		proposer_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal_account = banks_client.get_account(proposal_pda).await.unwrap();
+	assert!(proposal_account.is_none());
+
+	// The proposer paid one signature fee and got the rent back.
+	let proposer_account = banks_client.get_account(proposer_pubkey).await.unwrap().unwrap();
+	assert_eq!(
+		proposer_account.lamports,
+		1_000_000_000_000 - 5_000 + Rent::default().minimum_balance(1504),
+	);
+}
+
+#[tokio::test]
+async fn cancel_proposal_ix_rejects_proposer_after_approval() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let proposer_pubkey = proposer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [authority_pubkey, signer_pubkey, proposer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, proposer_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_proposal_ix_setup(
+		&proposer_keypair,
+		&[],
+		treasury_pda,
+		proposal_pda,
+		proposer_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn cancel_proposal_ix_rejects_single_signer_below_threshold() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let proposer_pubkey = proposer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [authority_pubkey, signer_pubkey, proposer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, proposer_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_proposal_ix_setup(
+		&authority_keypair,
+		&[],
+		treasury_pda,
+		proposal_pda,
+		proposer_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn cancel_proposal_ix_threshold_of_signers_cancels_approved_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let proposer_pubkey = proposer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [authority_pubkey, signer_pubkey, proposer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, proposer_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_proposal_ix_setup(
+		&authority_keypair,
+		&[&signer_keypair],
+		treasury_pda,
+		proposal_pda,
+		proposer_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal_account = banks_client.get_account(proposal_pda).await.unwrap();
+	assert!(proposal_account.is_none());
+}
+
+#[tokio::test]
+async fn cancel_proposal_ix_rejects_executed_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let proposer_pubkey = proposer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for pubkey in [authority_pubkey, signer_pubkey, proposer_pubkey] {
+		program_test.add_account(
+			pubkey,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, proposer_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Executed;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_proposal_ix_setup(
+		&authority_keypair,
+		&[&signer_keypair],
+		treasury_pda,
+		proposal_pda,
+		proposer_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalAlreadyExecuted);
+}
@@ -0,0 +1,190 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, process_transaction_with_events, recurring_payment_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, RecurringPaymentAction, RecurringPaymentChanged};
+
+
+#[tokio::test]
+async fn cancel_recurring_payment_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.created_by = authority_pubkey;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		authority_pubkey,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let (result, events) =
+		process_transaction_with_events::<RecurringPaymentChanged>(&mut banks_client, ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recurring_payment = banks_client.get_account(recurring_payment_pda).await.unwrap();
+	assert!(recurring_payment.is_none());
+
+	assert_eq!(
+		events,
+		vec![RecurringPaymentChanged {
+			recurring_payment: recurring_payment_pda,
+			treasury: treasury_pda,
+			actor: authority_pubkey,
+			action: RecurringPaymentAction::Cancelled,
+			old_amount: 1_000,
+			new_amount: 1_000,
+			old_destination: None,
+			new_destination: None,
+			old_next_payment_date: 0,
+			new_next_payment_date: 0,
+		}],
+	);
+}
+
+#[tokio::test]
+async fn cancel_recurring_payment_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.created_by = authority_pubkey;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cancel_recurring_payment_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		authority_pubkey,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
@@ -0,0 +1,358 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, get_program_account, proposal_fixture, treasury_fixture, voter_escrow_fixture};
+use treasury_management::{error::TreasuryManagementError, Proposal, ProposalStatus, VoteRecord, VoterEscrow};
+
+
+
+#[tokio::test]
+async fn cast_token_vote_ix_records_escrowed_weight() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (vote_record_pda, _vote_record_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_record",
+			proposal_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_program_account(&mut program_test, voter_escrow_pda, &voter_escrow_fixture(treasury_pda, voter_pubkey, 400), 88);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.voting_deadline = 4_000_000_000;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cast_token_vote_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		proposal_pda,
+		voter_escrow_pda,
+		vote_record_pda,
+		system_program::ID,
+		&name,
+		id,
+		true,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.votes_for, 400);
+	assert_eq!(proposal.status, ProposalStatus::Pending);
+
+	let vote_record: VoteRecord = get_program_account(&mut banks_client, vote_record_pda).await;
+	assert_eq!(vote_record.voter, voter_pubkey);
+	assert!(vote_record.vote_for);
+	assert_eq!(vote_record.weight, 400);
+	assert_eq!(vote_record.proposal_nonce, proposal.nonce);
+
+	let voter_escrow: VoterEscrow = get_program_account(&mut banks_client, voter_escrow_pda).await;
+	assert_eq!(voter_escrow.locked_until, 4_000_000_000);
+}
+
+#[tokio::test]
+async fn cast_token_vote_ix_rejects_signer_governed_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (vote_record_pda, _vote_record_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_record",
+			proposal_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_program_account(&mut program_test, voter_escrow_pda, &voter_escrow_fixture(treasury_pda, voter_pubkey, 400), 88);
+
+	// Opened before the treasury switched to token governance.
+	let proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cast_token_vote_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		proposal_pda,
+		voter_escrow_pda,
+		vote_record_pda,
+		system_program::ID,
+		&name,
+		id,
+		true,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::GovernanceModeMismatch);
+}
+
+#[tokio::test]
+async fn cast_token_vote_ix_ignores_record_from_closed_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (vote_record_pda, _vote_record_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_record",
+			proposal_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_program_account(&mut program_test, voter_escrow_pda, &voter_escrow_fixture(treasury_pda, voter_pubkey, 400), 88);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.voting_deadline = 4_000_000_000;
+	proposal.nonce = 1;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// Left open by an earlier proposal at the same PDA that was cancelled.
+	let stale_vote_record = VoteRecord {
+		proposal: proposal_pda,
+		voter: voter_pubkey,
+		vote_for: true,
+		weight: 250,
+		proposal_nonce: 0,
+	};
+	add_program_account(&mut program_test, vote_record_pda, &stale_vote_record, 89);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::cast_token_vote_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		proposal_pda,
+		voter_escrow_pda,
+		vote_record_pda,
+		system_program::ID,
+		&name,
+		id,
+		true,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.votes_for, 400);
+	assert_eq!(proposal.votes_against, 0);
+
+	let vote_record: VoteRecord = get_program_account(&mut banks_client, vote_record_pda).await;
+	assert!(vote_record.vote_for);
+	assert_eq!(vote_record.weight, 400);
+	assert_eq!(vote_record.proposal_nonce, 1);
+}
@@ -0,0 +1,300 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, payment_stream_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, PaymentStream};
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
+
+
+#[tokio::test]
+async fn claim_stream_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.created_by = authority_pubkey;
+	payment_stream.total_paid = 400;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::claim_stream_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payment_stream: PaymentStream = get_program_account(&mut banks_client, payment_stream_pda).await;
+	assert_eq!(payment_stream.total_paid, 1_000);
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 600);
+
+	let token_vault: TokenAccount = get_program_account(&mut banks_client, token_vault_pubkey).await;
+	assert_eq!(token_vault.amount, 999_400);
+}
+
+#[tokio::test]
+async fn claim_stream_ix_rejects_claim_before_cliff() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Cliff Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.created_by = authority_pubkey;
+	payment_stream.end_time = i64::MAX;
+	payment_stream.cliff_time = i64::MAX;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::claim_stream_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::PaymentStreamNotDue);
+}
+
+#[tokio::test]
+async fn claim_stream_ix_rejects_non_recipient() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, outsider_pubkey, 0);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.created_by = authority_pubkey;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::claim_stream_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
@@ -1,10 +1,23 @@
-use solana_program_test::ProgramTest;
+use anchor_lang::{
+    error::ERROR_CODE_OFFSET, AccountDeserialize, AccountSerialize, AnchorDeserialize, Discriminator, Event,
+    InstructionData, ToAccountMetas,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use anchor_spl::token::spl_token;
+use solana_program_test::{BanksClient, BanksClientError, ProgramTest};
 use solana_sdk::{
+    account::Account,
     hash::Hash,
-    instruction::Instruction,
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
     pubkey::Pubkey,
+    rent::Rent,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
+};
+use treasury_management::{
+    error::TreasuryManagementError, MilestonePayment, PaymentStream, Proposal, ProposalKind, ProposalStatus,
+    RecurringPayment, RecurringSchedule, Role, Treasury, TreasuryConfig, VoterEscrow,
 };
 
 pub fn get_program_test() -> ProgramTest {
@@ -15,439 +28,2159 @@ pub fn get_program_test() -> ProgramTest {
     )
 }
 
-pub mod treasury_management_ix_interface {
-    use super::*;
-    use solana_sdk::{system_program, instruction::AccountMeta};
-    use treasury_management::instruction::*;
+/// Asserts that a transaction failed with the given program error.
+pub fn assert_treasury_error(
+    result: Result<(), BanksClientError>,
+    expected: TreasuryManagementError,
+) {
+    let expected_code = ERROR_CODE_OFFSET + expected as u32;
+
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) => assert_eq!(code, expected_code, "unexpected error code"),
+        other => panic!("expected custom error {}, got {:?}", expected_code, other),
+    }
+}
+
+/// Processes a transaction and decodes every `T` event it emitted.
+pub async fn process_transaction_with_events<T: Event + AnchorDeserialize>(
+    banks_client: &mut BanksClient,
+    transaction: Transaction,
+) -> (Result<(), BanksClientError>, Vec<T>) {
+    let outcome = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    let events = outcome
+        .metadata
+        .map(|metadata| metadata.log_messages)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter(|data| data.starts_with(T::DISCRIMINATOR))
+        .map(|data| T::try_from_slice(&data[T::DISCRIMINATOR.len()..]).unwrap())
+        .collect();
+
+    (outcome.result.map_err(BanksClientError::TransactionError), events)
+}
+
+/// Seeds an initialized SPL mint.
+pub fn add_mint_account(
+    program_test: &mut ProgramTest,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    decimals: u8,
+) {
+    add_mint_account_with_supply(program_test, mint, mint_authority, decimals, u64::MAX / 2);
+}
+
+/// Seeds an initialized SPL mint with a specific total supply.
+pub fn add_mint_account_with_supply(
+    program_test: &mut ProgramTest,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    decimals: u8,
+    supply: u64,
+) {
+    let mut data = vec![0u8; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(
+        spl_token::state::Mint {
+            mint_authority: Some(mint_authority).into(),
+            supply,
+            decimals,
+            is_initialized: true,
+            freeze_authority: None.into(),
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    program_test.add_account(
+        mint,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Seeds an initialized SPL token account holding `amount` of `mint`.
+pub fn add_token_account(
+    program_test: &mut ProgramTest,
+    address: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) {
+    let mut data = vec![0u8; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(
+        spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            delegate: None.into(),
+            state: spl_token::state::AccountState::Initialized,
+            is_native: None.into(),
+            delegated_amount: 0,
+            close_authority: None.into(),
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    program_test.add_account(
+        address,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// Seeds a program-owned account with already-serialized Anchor state, so a
+/// test can exercise an instruction without replaying the whole setup flow.
+pub async fn get_program_account<T: AccountDeserialize>(
+    banks_client: &mut BanksClient,
+    address: Pubkey,
+) -> T {
+    let account = banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .expect("account not found");
+
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+pub fn add_program_account<T: AccountSerialize + Discriminator>(
+    program_test: &mut ProgramTest,
+    address: Pubkey,
+    state: &T,
+    space: usize,
+) {
+    let mut data = Vec::with_capacity(space);
+    state.try_serialize(&mut data).unwrap();
+    data.resize(space, 0);
+
+    program_test.add_account(
+        address,
+        Account {
+            lamports: Rent::default().minimum_balance(space),
+            data,
+            owner: treasury_management::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+/// A single-signer treasury controlled by `authority`.
+pub fn treasury_fixture(name: &str, authority: Pubkey) -> Treasury {
+    Treasury {
+        name: name.to_string(),
+        authority,
+        signers: vec![authority],
+        signer_weights: vec![1],
+        threshold: 1,
+        is_paused: false,
+        total_deposited: 0,
+        total_withdrawn: 0,
+        created_at: 0,
+        admin_limit: 1_000_000_000,
+        treasurer_limit: 500_000_000,
+        contributor_limit: 100_000_000,
+        reset_period: 604800,
+        auto_stake: false,
+        stake_target_percentage: 0,
+        whitelist_enabled: false,
+        voting_period: 259_200,
+        execution_grace_period: 604_800,
+        execution_delay: 86_400,
+        execution_delay_amount_step: 0,
+        governance_mint: None,
+        quorum_percentage: 10,
+        approval_percentage: 50,
+        governance_deposits: 0,
+        stream_count: 0,
+        proposal_count: 0,
+    }
+}
+
+pub fn role_fixture(treasury: Pubkey, user: Pubkey, role_type: u8) -> Role {
+    Role {
+        treasury,
+        user,
+        role_type,
+        can_execute_payments: true,
+        can_create_streams: true,
+        can_manage_roles: false,
+        can_view_treasury: true,
+        can_propose: true,
+        can_vote: true,
+        spending_limit_used: 0,
+        last_limit_reset: 0,
+    }
+}
+
+pub fn proposal_fixture(treasury: Pubkey, proposer: Pubkey, token_mint: Pubkey, recipient: Pubkey) -> Proposal {
+    Proposal {
+        treasury,
+        proposer,
+        title: "Fund audit".to_string(),
+        description: "Pay the auditors".to_string(),
+        amount: 1_000,
+        token_mint,
+        recipient,
+        votes_for: 0,
+        votes_against: 0,
+        status: ProposalStatus::Pending,
+        created_at: 0,
+        voting_deadline: i64::MAX,
+        execution_deadline: i64::MAX,
+        approved_at: 0,
+        executable_at: 0,
+        executed_at: 0,
+        votes: vec![],
+        kind: ProposalKind::Transfer,
+        instructions: vec![],
+        config: None,
+        governance_mint: None,
+        nonce: 0,
+    }
+}
+
+pub fn treasury_config_fixture(signers: Vec<Pubkey>, threshold: u8) -> TreasuryConfig {
+    TreasuryConfig {
+        signer_weights: vec![1; signers.len()],
+        signers,
+        threshold,
+        admin_limit: 2_000_000_000,
+        treasurer_limit: 1_000_000_000,
+        contributor_limit: 200_000_000,
+        reset_period: 86_400,
+        auto_stake: false,
+        stake_target_percentage: 0,
+        voting_period: 86_400,
+        execution_grace_period: 172_800,
+        execution_delay: 3_600,
+        execution_delay_amount_step: 0,
+        governance_mint: None,
+        quorum_percentage: 10,
+        approval_percentage: 50,
+    }
+}
+
+pub fn voter_escrow_fixture(treasury: Pubkey, owner: Pubkey, amount: u64) -> VoterEscrow {
+    VoterEscrow {
+        treasury,
+        owner,
+        amount,
+        locked_until: 0,
+    }
+}
+
+/// A stream of 1_000 `token_mint` that has fully vested by the time any test
+/// runs.
+pub fn payment_stream_fixture(treasury: Pubkey, recipient: Pubkey, token_mint: Pubkey) -> PaymentStream {
+    PaymentStream {
+        treasury,
+        id: 0,
+        recipient,
+        token_mint,
+        amount_per_period: 100,
+        period_duration: 100,
+        start_time: 0,
+        end_time: 1_000,
+        cliff_time: 0,
+        accrued: 0,
+        accrued_until: 0,
+        budget: 1_000,
+        is_paused: false,
+        last_payment_time: 0,
+        total_paid: 0,
+        is_active: true,
+        category: 0,
+        created_by: Pubkey::default(),
+        transfer_requires_approval: false,
+        charge_period: 0,
+    }
+}
+
+/// An open milestone paying 1_000 `token_mint`, created by `created_by`.
+pub fn milestone_payment_fixture(treasury: Pubkey, recipient: Pubkey, token_mint: Pubkey, created_by: Pubkey) -> MilestonePayment {
+    MilestonePayment {
+        treasury,
+        recipient,
+        token_mint,
+        amount: 1_000,
+        description: "Audit report".to_string(),
+        is_completed: false,
+        created_at: 0,
+        completed_at: 0,
+        created_by,
+        category: 0,
+        charge_period: 0,
+    }
+}
+
+/// 1_000 `token_mint` every 100 seconds since the epoch, with no cap or end
+/// date, so plenty of installments are overdue by the time any test runs.
+pub fn recurring_payment_fixture(treasury: Pubkey, recipient: Pubkey, token_mint: Pubkey) -> RecurringPayment {
+    RecurringPayment {
+        treasury,
+        recipient,
+        token_mint,
+        amount: 1_000,
+        interval: 100,
+        next_payment_date: 0,
+        is_active: true,
+        created_by: Pubkey::default(),
+        category: 0,
+        payments_made: 0,
+        max_payments: None,
+        end_date: None,
+        schedule: RecurringSchedule::Interval,
+        is_paused: false,
+        destination: None,
+    }
+}
+
+pub fn signed_transaction(
+    instruction: Instruction,
+    payer: &Keypair,
+    recent_blockhash: Hash,
+) -> Transaction {
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[payer], recent_blockhash);
+
+    transaction
+}
+
+pub mod treasury_management_ix_interface {
+    use super::*;
+    use solana_sdk::instruction::AccountMeta;
+    use treasury_management::{accounts, instruction, ProposalInstruction, TreasuryConfig};
+
+    // Initialize Treasury
+    pub fn initialize_treasury_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        signers: &[Pubkey],
+        signer_weights: &[u8],
+        threshold: u8,
+        admin_limit: u64,
+        treasurer_limit: u64,
+        contributor_limit: u64,
+        reset_period: u64,
+        auto_stake: bool,
+        stake_target_percentage: u8,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::InitializeTreasury {
+            authority: authority.pubkey(),
+            treasury,
+            system_program,
+        };
+
+        let data = instruction::InitializeTreasury {
+            name: name.to_string(),
+            signers: signers.to_vec(),
+            signer_weights: signer_weights.to_vec(),
+            threshold,
+            admin_limit,
+            treasurer_limit,
+            contributor_limit,
+            reset_period,
+            auto_stake,
+            stake_target_percentage,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Update Treasury Config
+    pub fn update_treasury_config_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        name: &str,
+        new_signers: &[Pubkey],
+        new_signer_weights: &[u8],
+        new_threshold: u8,
+        admin_limit: u64,
+        treasurer_limit: u64,
+        contributor_limit: u64,
+        reset_period: u64,
+        auto_stake: bool,
+        stake_target_percentage: u8,
+        voting_period: i64,
+        execution_grace_period: i64,
+        execution_delay: i64,
+        execution_delay_amount_step: u64,
+        governance_mint: Option<Pubkey>,
+        quorum_percentage: u8,
+        approval_percentage: u8,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::UpdateTreasuryConfig {
+            authority: authority.pubkey(),
+            treasury,
+        };
+
+        let data = instruction::UpdateTreasuryConfig {
+            name: name.to_string(),
+            new_signers: new_signers.to_vec(),
+            new_signer_weights: new_signer_weights.to_vec(),
+            new_threshold,
+            admin_limit,
+            treasurer_limit,
+            contributor_limit,
+            reset_period,
+            auto_stake,
+            stake_target_percentage,
+            voting_period,
+            execution_grace_period,
+            execution_delay,
+            execution_delay_amount_step,
+            governance_mint,
+            quorum_percentage,
+            approval_percentage,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Deposit Tokens
+    pub fn deposit_tokens_ix_setup(
+        depositor: &Keypair,
+        treasury: Pubkey,
+        token_mint: Pubkey,
+        source: Pubkey,
+        token_vault: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::DepositTokens {
+            depositor: depositor.pubkey(),
+            treasury,
+            token_mint,
+            source,
+            token_vault,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::DepositTokens {
+            name: name.to_string(),
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, depositor, recent_blockhash)
+    }
+
+    // Deposit SOL
+    pub fn deposit_sol_ix_setup(
+        depositor: &Keypair,
+        treasury: Pubkey,
+        sol_vault: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::DepositSol {
+            depositor: depositor.pubkey(),
+            treasury,
+            sol_vault,
+            system_program,
+        };
+
+        let data = instruction::DepositSol {
+            name: name.to_string(),
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, depositor, recent_blockhash)
+    }
+
+    // Withdraw Tokens
+    pub fn withdraw_tokens_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        token_mint: Pubkey,
+        recipient: Pubkey,
+        token_vault: Pubkey,
+        destination: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::WithdrawTokens {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            token_mint,
+            recipient,
+            token_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::WithdrawTokens {
+            name: name.to_string(),
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Withdraw SOL
+    pub fn withdraw_sol_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        sol_vault: Pubkey,
+        recipient: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::WithdrawSol {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            sol_vault,
+            recipient,
+            system_program,
+        };
+
+        let data = instruction::WithdrawSol {
+            name: name.to_string(),
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Assign Role
+    pub fn assign_role_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        role: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        user: Pubkey,
+        role_type: u8,
+        can_execute_payments: bool,
+        can_create_streams: bool,
+        can_manage_roles: bool,
+        can_view_treasury: bool,
+        can_propose: bool,
+        can_vote: bool,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::AssignRole {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            role,
+            system_program,
+        };
+
+        let data = instruction::AssignRole {
+            name: name.to_string(),
+            user,
+            role_type,
+            can_execute_payments,
+            can_create_streams,
+            can_manage_roles,
+            can_view_treasury,
+            can_propose,
+            can_vote,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Remove Role
+    pub fn remove_role_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        role: Pubkey,
+        name: &str,
+        user: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::RemoveRole {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            role,
+        };
+
+        let data = instruction::RemoveRole {
+            name: name.to_string(),
+            user,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Create Payment Stream
+    pub fn create_payment_stream_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        stream_id: u64,
+        recipient: Pubkey,
+        token_mint: Pubkey,
+        amount_per_period: u64,
+        period_duration: i64,
+        start_time: i64,
+        end_time: i64,
+        cliff_time: Option<i64>,
+        category: u8,
+        transfer_requires_approval: bool,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CreatePaymentStream {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            payment_stream,
+            system_program,
+        };
+
+        let data = instruction::CreatePaymentStream {
+            name: name.to_string(),
+            stream_id,
+            recipient,
+            token_mint,
+            amount_per_period,
+            period_duration,
+            start_time,
+            end_time,
+            cliff_time,
+            category,
+            transfer_requires_approval,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Execute Stream Payment
+    pub fn execute_stream_payment_ix_setup(
+        executor: &Keypair,
+        treasury: Pubkey,
+        executor_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        destination: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        stream_id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::ExecuteStreamPayment {
+            executor: executor.pubkey(),
+            treasury,
+            executor_role,
+            payment_stream,
+            token_mint,
+            token_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::ExecuteStreamPayment {
+            name: name.to_string(),
+            stream_id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, executor, recent_blockhash)
+    }
+
+    // Claim Stream
+    pub fn claim_stream_ix_setup(
+        recipient: &Keypair,
+        treasury: Pubkey,
+        payment_stream: Pubkey,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        destination: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        stream_id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::ClaimStream {
+            recipient: recipient.pubkey(),
+            treasury,
+            payment_stream,
+            token_mint,
+            token_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::ClaimStream {
+            name: name.to_string(),
+            stream_id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, recipient, recent_blockhash)
+    }
+
+    // Cancel Payment Stream
+    pub fn cancel_payment_stream_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        creator: Pubkey,
+        creator_role: Option<Pubkey>,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        destination: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        stream_id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CancelPaymentStream {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            payment_stream,
+            creator,
+            creator_role,
+            token_mint,
+            token_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::CancelPaymentStream {
+            name: name.to_string(),
+            stream_id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Pause Payment Stream
+    pub fn pause_payment_stream_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        name: &str,
+        stream_id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::PausePaymentStream {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            payment_stream,
+        };
+
+        let data = instruction::PausePaymentStream {
+            name: name.to_string(),
+            stream_id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Resume Payment Stream
+    pub fn resume_payment_stream_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        name: &str,
+        stream_id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::ResumePaymentStream {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            payment_stream,
+        };
+
+        let data = instruction::ResumePaymentStream {
+            name: name.to_string(),
+            stream_id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Update Payment Stream
+    pub fn update_payment_stream_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        name: &str,
+        stream_id: u64,
+        amount_per_period: Option<u64>,
+        end_time: Option<i64>,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::UpdatePaymentStream {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            payment_stream,
+        };
+
+        let data = instruction::UpdatePaymentStream {
+            name: name.to_string(),
+            stream_id,
+            amount_per_period,
+            end_time,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Top Up Payment Stream
+    pub fn top_up_payment_stream_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        payment_stream: Pubkey,
+        name: &str,
+        stream_id: u64,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::TopUpPaymentStream {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            payment_stream,
+        };
+
+        let data = instruction::TopUpPaymentStream {
+            name: name.to_string(),
+            stream_id,
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Transfer Stream Recipient
+    pub fn transfer_stream_recipient_ix_setup(
+        recipient: &Keypair,
+        treasury: Pubkey,
+        payment_stream: Pubkey,
+        approver: Option<&Keypair>,
+        approver_role: Option<Pubkey>,
+        name: &str,
+        stream_id: u64,
+        new_recipient: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::TransferStreamRecipient {
+            recipient: recipient.pubkey(),
+            treasury,
+            payment_stream,
+            approver: approver.map(|approver| approver.pubkey()),
+            approver_role,
+        };
+
+        let data = instruction::TransferStreamRecipient {
+            name: name.to_string(),
+            stream_id,
+            new_recipient,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&recipient.pubkey()));
+        let mut signers = vec![recipient];
+        signers.extend(approver);
+        transaction.sign(&signers, recent_blockhash);
+        transaction
+    }
+
+    // Create Milestone Payment
+    pub fn create_milestone_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        milestone_payment: Pubkey,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        escrow_authority: Pubkey,
+        escrow_vault: Pubkey,
+        token_program: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        id: u64,
+        recipient: Pubkey,
+        amount: u64,
+        description: &str,
+        category: u8,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CreateMilestonePayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            milestone_payment,
+            token_mint,
+            token_vault,
+            escrow_authority,
+            escrow_vault,
+            csl_spl_token_v0_0_0: token_program,
+            system_program,
+        };
+
+        let data = instruction::CreateMilestonePayment {
+            name: name.to_string(),
+            id,
+            recipient,
+            amount,
+            description: description.to_string(),
+            category,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Complete Milestone
+    pub fn complete_milestone_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        milestone_payment: Pubkey,
+        creator: Pubkey,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        escrow_authority: Pubkey,
+        escrow_vault: Pubkey,
+        destination: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CompleteMilestone {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            milestone_payment,
+            creator,
+            token_mint,
+            token_vault,
+            escrow_authority,
+            escrow_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::CompleteMilestone {
+            name: name.to_string(),
+            id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Cancel Milestone Payment
+    pub fn cancel_milestone_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        milestone_payment: Pubkey,
+        creator: Pubkey,
+        creator_role: Option<Pubkey>,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        escrow_authority: Pubkey,
+        escrow_vault: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CancelMilestonePayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            milestone_payment,
+            creator,
+            creator_role,
+            token_mint,
+            token_vault,
+            escrow_authority,
+            escrow_vault,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::CancelMilestonePayment {
+            name: name.to_string(),
+            id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Create Recurring Payment
+    pub fn create_recurring_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        recipient: Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+        interval: i64,
+        category: u8,
+        max_payments: Option<u32>,
+        end_date: Option<i64>,
+        schedule: RecurringSchedule,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CreateRecurringPayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            recurring_payment,
+            system_program,
+        };
+
+        let data = instruction::CreateRecurringPayment {
+            name: name.to_string(),
+            recipient,
+            token_mint,
+            amount,
+            interval,
+            category,
+            max_payments,
+            end_date,
+            schedule,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Execute Recurring Payment
+    pub fn execute_recurring_payment_ix_setup(
+        executor: &Keypair,
+        treasury: Pubkey,
+        executor_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        destination: Pubkey,
+        token_program: Pubkey,
+        name: &str,
+        recipient: Pubkey,
+        max_intervals: u8,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::ExecuteRecurringPayment {
+            executor: executor.pubkey(),
+            treasury,
+            executor_role,
+            recurring_payment,
+            token_mint,
+            token_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
+
+        let data = instruction::ExecuteRecurringPayment {
+            name: name.to_string(),
+            recipient,
+            max_intervals,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, executor, recent_blockhash)
+    }
+
+    // Pause Recurring Payment
+    pub fn pause_recurring_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        name: &str,
+        recipient: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::PauseRecurringPayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            recurring_payment,
+        };
+
+        let data = instruction::PauseRecurringPayment {
+            name: name.to_string(),
+            recipient,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Resume Recurring Payment
+    pub fn resume_recurring_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        name: &str,
+        recipient: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::ResumeRecurringPayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            recurring_payment,
+        };
+
+        let data = instruction::ResumeRecurringPayment {
+            name: name.to_string(),
+            recipient,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Skip Recurring Payment
+    pub fn skip_recurring_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        name: &str,
+        recipient: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::SkipRecurringPayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            recurring_payment,
+        };
+
+        let data = instruction::SkipRecurringPayment {
+            name: name.to_string(),
+            recipient,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Update Recurring Payment
+    pub fn update_recurring_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        destination: Option<Pubkey>,
+        name: &str,
+        recipient: Pubkey,
+        amount: Option<u64>,
+        clear_destination: bool,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::UpdateRecurringPayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            recurring_payment,
+            destination,
+        };
+
+        let data = instruction::UpdateRecurringPayment {
+            name: name.to_string(),
+            recipient,
+            amount,
+            clear_destination,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Cancel Recurring Payment
+    pub fn cancel_recurring_payment_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        recurring_payment: Pubkey,
+        creator: Pubkey,
+        name: &str,
+        recipient: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CancelRecurringPayment {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            recurring_payment,
+            creator,
+        };
+
+        let data = instruction::CancelRecurringPayment {
+            name: name.to_string(),
+            recipient,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Batch Transfer
+    pub fn batch_transfer_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        token_mint: Pubkey,
+        token_vault: Pubkey,
+        destinations: Vec<Pubkey>,
+        token_program: Pubkey,
+        name: &str,
+        recipients: Vec<Pubkey>,
+        amounts: Vec<u64>,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let mut accounts = accounts::BatchTransfer {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            token_mint,
+            token_vault,
+            csl_spl_token_v0_0_0: token_program,
+        }
+        .to_account_metas(None);
+
+        // Add destination accounts
+        for dest in destinations {
+            accounts.push(AccountMeta::new(dest, false));
+        }
+
+        let data = instruction::BatchTransfer {
+            name: name.to_string(),
+            recipients,
+            amounts,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts,
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Stake SOL for Yield
+    pub fn stake_sol_for_yield_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        name: &str,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::StakeSolForYield {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+        };
+
+        let data = instruction::StakeSolForYield {
+            name: name.to_string(),
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Unstake SOL
+    pub fn unstake_sol_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        name: &str,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::UnstakeSol {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+        };
+
+        let data = instruction::UnstakeSol {
+            name: name.to_string(),
+            amount,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Create Proposal
+    pub fn create_proposal_ix_setup(
+        proposer: &Keypair,
+        treasury: Pubkey,
+        proposer_role: Option<Pubkey>,
+        proposal: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        id: u64,
+        title: &str,
+        description: &str,
+        amount: u64,
+        token_mint: Pubkey,
+        recipient: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CreateProposal {
+            proposer: proposer.pubkey(),
+            treasury,
+            proposer_role,
+            proposal,
+            system_program,
+        };
+
+        let data = instruction::CreateProposal {
+            name: name.to_string(),
+            id,
+            title: title.to_string(),
+            description: description.to_string(),
+            amount,
+            token_mint,
+            recipient,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, proposer, recent_blockhash)
+    }
+
+    // Create Instruction Proposal
+    pub fn create_instruction_proposal_ix_setup(
+        proposer: &Keypair,
+        treasury: Pubkey,
+        proposer_role: Option<Pubkey>,
+        proposal: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        id: u64,
+        title: &str,
+        description: &str,
+        instructions: Vec<ProposalInstruction>,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CreateInstructionProposal {
+            proposer: proposer.pubkey(),
+            treasury,
+            proposer_role,
+            proposal,
+            system_program,
+        };
+
+        let data = instruction::CreateInstructionProposal {
+            name: name.to_string(),
+            id,
+            title: title.to_string(),
+            description: description.to_string(),
+            instructions,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, proposer, recent_blockhash)
+    }
+
+    // Create Config Proposal
+    pub fn create_config_proposal_ix_setup(
+        proposer: &Keypair,
+        treasury: Pubkey,
+        proposer_role: Option<Pubkey>,
+        proposal: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        id: u64,
+        title: &str,
+        description: &str,
+        config: TreasuryConfig,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::CreateConfigProposal {
+            proposer: proposer.pubkey(),
+            treasury,
+            proposer_role,
+            proposal,
+            system_program,
+        };
+
+        let data = instruction::CreateConfigProposal {
+            name: name.to_string(),
+            id,
+            title: title.to_string(),
+            description: description.to_string(),
+            config,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, proposer, recent_blockhash)
+    }
+
+    // Vote on Proposal
+    pub fn vote_on_proposal_ix_setup(
+        voter: &Keypair,
+        treasury: Pubkey,
+        voter_role: Option<Pubkey>,
+        proposal: Pubkey,
+        delegation: Option<Pubkey>,
+        name: &str,
+        id: u64,
+        vote_for: bool,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::VoteOnProposal {
+            voter: voter.pubkey(),
+            treasury,
+            voter_role,
+            proposal,
+            delegation,
+        };
+
+        let data = instruction::VoteOnProposal {
+            name: name.to_string(),
+            id,
+            vote_for,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, voter, recent_blockhash)
+    }
+
+    // Delegate Vote
+    pub fn delegate_vote_ix_setup(
+        delegator: &Keypair,
+        treasury: Pubkey,
+        delegation: Pubkey,
+        system_program: Pubkey,
+        name: &str,
+        delegate: Pubkey,
+        expires_at: Option<i64>,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::DelegateVote {
+            delegator: delegator.pubkey(),
+            treasury,
+            delegation,
+            system_program,
+        };
+
+        let data = instruction::DelegateVote {
+            name: name.to_string(),
+            delegate,
+            expires_at,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, delegator, recent_blockhash)
+    }
+
+    // Revoke Delegation
+    pub fn revoke_delegation_ix_setup(
+        delegator: &Keypair,
+        treasury: Pubkey,
+        delegation: Pubkey,
+        name: &str,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::RevokeDelegation {
+            delegator: delegator.pubkey(),
+            treasury,
+            delegation,
+        };
+
+        let data = instruction::RevokeDelegation {
+            name: name.to_string(),
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, delegator, recent_blockhash)
+    }
+
+    // Revoke Vote
+    pub fn revoke_vote_ix_setup(
+        voter: &Keypair,
+        treasury: Pubkey,
+        proposal: Pubkey,
+        name: &str,
+        id: u64,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::RevokeVote {
+            voter: voter.pubkey(),
+            treasury,
+            proposal,
+        };
+
+        let data = instruction::RevokeVote {
+            name: name.to_string(),
+            id,
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, voter, recent_blockhash)
+    }
 
-    // Initialize Treasury
-    pub fn initialize_treasury_ix_setup(
-        authority: &Keypair,
+    // Deposit Governance Tokens
+    pub fn deposit_governance_tokens_ix_setup(
+        voter: &Keypair,
         treasury: Pubkey,
+        governance_mint: Pubkey,
+        source: Pubkey,
+        escrow_authority: Pubkey,
+        escrow_vault: Pubkey,
+        voter_escrow: Pubkey,
+        token_program: Pubkey,
         system_program: Pubkey,
         name: &str,
-        signers: &[Pubkey],
-        threshold: u8,
-        admin_limit: u64,
-        treasurer_limit: u64,
-        contributor_limit: u64,
-        reset_period: u64,
-        auto_stake: bool,
-        stake_target_percentage: u8,
+        amount: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new_readonly(system_program, false),
-        ];
+        let accounts = accounts::DepositGovernanceTokens {
+            voter: voter.pubkey(),
+            treasury,
+            governance_mint,
+            source,
+            escrow_authority,
+            escrow_vault,
+            voter_escrow,
+            csl_spl_token_v0_0_0: token_program,
+            system_program,
+        };
 
-        let data = InitializeTreasuryArgs {
+        let data = instruction::DepositGovernanceTokens {
             name: name.to_string(),
-            signers: signers.to_vec(),
-            threshold,
-            admin_limit,
-            treasurer_limit,
-            contributor_limit,
-            reset_period,
-            auto_stake,
-            stake_target_percentage,
+            amount,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::InitializeTreasury(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, voter, recent_blockhash)
     }
 
-    // Assign Role
-    pub fn assign_role_ix_setup(
-        authority: &Keypair,
+    // Withdraw Governance Tokens
+    pub fn withdraw_governance_tokens_ix_setup(
+        voter: &Keypair,
         treasury: Pubkey,
+        governance_mint: Pubkey,
+        destination: Pubkey,
+        escrow_authority: Pubkey,
+        escrow_vault: Pubkey,
+        voter_escrow: Pubkey,
+        token_program: Pubkey,
         name: &str,
-        user: Pubkey,
-        role_type: u8,
-        can_execute_payments: bool,
-        can_create_streams: bool,
-        can_manage_roles: bool,
-        can_view_treasury: bool,
-        can_propose: bool,
-        can_vote: bool,
+        amount: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new(treasury, false),
-        ];
+        let accounts = accounts::WithdrawGovernanceTokens {
+            voter: voter.pubkey(),
+            treasury,
+            governance_mint,
+            destination,
+            escrow_authority,
+            escrow_vault,
+            voter_escrow,
+            csl_spl_token_v0_0_0: token_program,
+        };
 
-        let data = AssignRoleArgs {
+        let data = instruction::WithdrawGovernanceTokens {
             name: name.to_string(),
-            user,
-            role_type,
-            can_execute_payments,
-            can_create_streams,
-            can_manage_roles,
-            can_view_treasury,
-            can_propose,
-            can_vote,
+            amount,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::AssignRole(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, voter, recent_blockhash)
     }
 
-    // Create Payment Stream
-    pub fn create_payment_stream_ix_setup(
-        authority: &Keypair,
+    // Cast Token Vote
+    pub fn cast_token_vote_ix_setup(
+        voter: &Keypair,
         treasury: Pubkey,
-        payment_stream: Pubkey,
+        proposal: Pubkey,
+        voter_escrow: Pubkey,
+        vote_record: Pubkey,
         system_program: Pubkey,
         name: &str,
-        recipient: Pubkey,
-        token_mint: Pubkey,
-        amount_per_period: u64,
-        period_duration: i64,
-        start_time: i64,
-        end_time: i64,
-        category: u8,
+        id: u64,
+        vote_for: bool,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new_readonly(treasury, false),
-            AccountMeta::new(payment_stream, false),
-            AccountMeta::new_readonly(system_program, false),
-        ];
+        let accounts = accounts::CastTokenVote {
+            voter: voter.pubkey(),
+            treasury,
+            proposal,
+            voter_escrow,
+            vote_record,
+            system_program,
+        };
 
-        let data = CreatePaymentStreamArgs {
+        let data = instruction::CastTokenVote {
             name: name.to_string(),
-            recipient,
-            token_mint,
-            amount_per_period,
-            period_duration,
-            start_time,
-            end_time,
-            category,
+            id,
+            vote_for,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::CreatePaymentStream(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, voter, recent_blockhash)
     }
 
-    // Execute Stream Payment
-    pub fn execute_stream_payment_ix_setup(
-        executor: &Keypair,
+    // Revoke Token Vote
+    pub fn revoke_token_vote_ix_setup(
+        voter: &Keypair,
         treasury: Pubkey,
-        payment_stream: Pubkey,
-        token_mint: Pubkey,
-        source: Pubkey,
-        destination: Pubkey,
-        token_program: Pubkey,
+        proposal: Pubkey,
+        vote_record: Pubkey,
         name: &str,
-        recipient: Pubkey,
+        id: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(executor.pubkey(), true),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new(payment_stream, false),
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(source, false),
-            AccountMeta::new(destination, false),
-            AccountMeta::new_readonly(token_program, false),
-        ];
+        let accounts = accounts::RevokeTokenVote {
+            voter: voter.pubkey(),
+            treasury,
+            proposal,
+            vote_record,
+        };
 
-        let data = ExecuteStreamPaymentArgs {
+        let data = instruction::RevokeTokenVote {
             name: name.to_string(),
-            recipient,
+            id,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::ExecuteStreamPayment(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&executor.pubkey()));
-        transaction.sign(&[executor], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, voter, recent_blockhash)
     }
 
-    // Create Milestone Payment
-    pub fn create_milestone_payment_ix_setup(
-        authority: &Keypair,
+    // Finalize Proposal
+    pub fn finalize_proposal_ix_setup(
+        caller: &Keypair,
         treasury: Pubkey,
-        milestone_payment: Pubkey,
-        system_program: Pubkey,
+        proposal: Pubkey,
+        governance_mint: Pubkey,
         name: &str,
         id: u64,
-        recipient: Pubkey,
-        token_mint: Pubkey,
-        amount: u64,
-        description: &str,
-        category: u8,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new_readonly(treasury, false),
-            AccountMeta::new(milestone_payment, false),
-            AccountMeta::new_readonly(system_program, false),
-        ];
+        let accounts = accounts::FinalizeProposal {
+            caller: caller.pubkey(),
+            treasury,
+            proposal,
+            governance_mint,
+        };
 
-        let data = CreateMilestonePaymentArgs {
+        let data = instruction::FinalizeProposal {
             name: name.to_string(),
             id,
-            recipient,
-            token_mint,
-            amount,
-            description: description.to_string(),
-            category,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::CreateMilestonePayment(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, caller, recent_blockhash)
     }
 
-    // Complete Milestone
-    pub fn complete_milestone_ix_setup(
-        authority: &Keypair,
+    // Execute Proposal
+    pub fn execute_proposal_ix_setup(
+        executor: &Keypair,
         treasury: Pubkey,
-        milestone_payment: Pubkey,
+        executor_role: Option<Pubkey>,
+        proposal: Pubkey,
         token_mint: Pubkey,
-        source: Pubkey,
+        token_vault: Pubkey,
         destination: Pubkey,
         token_program: Pubkey,
         name: &str,
         id: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new(milestone_payment, false),
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(source, false),
-            AccountMeta::new(destination, false),
-            AccountMeta::new_readonly(token_program, false),
-        ];
+        let accounts = accounts::ExecuteProposal {
+            executor: executor.pubkey(),
+            treasury,
+            executor_role,
+            proposal,
+            token_mint,
+            token_vault,
+            destination,
+            csl_spl_token_v0_0_0: token_program,
+        };
 
-        let data = CompleteMilestoneArgs {
+        let data = instruction::ExecuteProposal {
             name: name.to_string(),
             id,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::CompleteMilestone(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, executor, recent_blockhash)
     }
 
-    // Batch Transfer
-    pub fn batch_transfer_ix_setup(
-        authority: &Keypair,
+    // Execute Instruction Proposal
+    pub fn execute_instruction_proposal_ix_setup(
+        executor: &Keypair,
         treasury: Pubkey,
-        token_mint: Pubkey,
-        source: Pubkey,
-        destinations: Vec<Pubkey>,
-        token_program: Pubkey,
+        executor_role: Option<Pubkey>,
+        proposal: Pubkey,
+        remaining_accounts: Vec<AccountMeta>,
         name: &str,
-        recipients: Vec<Pubkey>,
-        amounts: Vec<u64>,
+        id: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let mut accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(source, false),
-            AccountMeta::new_readonly(token_program, false),
-        ];
-
-        // Add destination accounts
-        for dest in destinations {
-            accounts.push(AccountMeta::new(dest, false));
+        let mut accounts = accounts::ExecuteInstructionProposal {
+            executor: executor.pubkey(),
+            treasury,
+            executor_role,
+            proposal,
         }
+        .to_account_metas(None);
+
+        // Programs and accounts the proposal's instructions touch
+        accounts.extend(remaining_accounts);
 
-        let data = BatchTransferArgs {
+        let data = instruction::ExecuteInstructionProposal {
             name: name.to_string(),
-            recipients,
-            amounts,
+            id,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::BatchTransfer(data),
+            &data.data(),
             accounts,
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, executor, recent_blockhash)
     }
 
-    // Create Proposal
-    pub fn create_proposal_ix_setup(
-        proposer: &Keypair,
+    // Execute Config Proposal
+    pub fn execute_config_proposal_ix_setup(
+        executor: &Keypair,
         treasury: Pubkey,
         proposal: Pubkey,
-        system_program: Pubkey,
         name: &str,
         id: u64,
-        title: &str,
-        description: &str,
-        amount: u64,
-        token_mint: Pubkey,
-        recipient: Pubkey,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(proposer.pubkey(), true),
-            AccountMeta::new_readonly(treasury, false),
-            AccountMeta::new(proposal, false),
-            AccountMeta::new_readonly(system_program, false),
-        ];
+        let accounts = accounts::ExecuteConfigProposal {
+            executor: executor.pubkey(),
+            treasury,
+            proposal,
+        };
 
-        let data = CreateProposalArgs {
+        let data = instruction::ExecuteConfigProposal {
             name: name.to_string(),
             id,
-            title: title.to_string(),
-            description: description.to_string(),
-            amount,
-            token_mint,
-            recipient,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::CreateProposal(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&proposer.pubkey()));
-        transaction.sign(&[proposer], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, executor, recent_blockhash)
     }
 
-    // Vote on Proposal
-    pub fn vote_on_proposal_ix_setup(
-        voter: &Keypair,
+    // Expire Proposal
+    pub fn expire_proposal_ix_setup(
+        caller: &Keypair,
         treasury: Pubkey,
         proposal: Pubkey,
+        proposer: Pubkey,
         name: &str,
         id: u64,
-        vote_for: bool,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(voter.pubkey(), true),
-            AccountMeta::new_readonly(treasury, false),
-            AccountMeta::new(proposal, false),
-        ];
+        let accounts = accounts::ExpireProposal {
+            caller: caller.pubkey(),
+            treasury,
+            proposal,
+            proposer,
+        };
 
-        let data = VoteOnProposalArgs {
+        let data = instruction::ExpireProposal {
             name: name.to_string(),
             id,
-            vote_for,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::VoteOnProposal(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&voter.pubkey()));
-        transaction.sign(&[voter], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, caller, recent_blockhash)
     }
 
-    // Execute Proposal
-    pub fn execute_proposal_ix_setup(
-        executor: &Keypair,
+    // Cancel Proposal
+    pub fn cancel_proposal_ix_setup(
+        authority: &Keypair,
+        co_signers: &[&Keypair],
         treasury: Pubkey,
         proposal: Pubkey,
-        token_mint: Pubkey,
-        source: Pubkey,
-        destination: Pubkey,
-        authority: &Keypair,
-        token_program: Pubkey,
+        proposer: Pubkey,
         name: &str,
         id: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(executor.pubkey(), true),
-            AccountMeta::new(treasury, false),
-            AccountMeta::new(proposal, false),
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(source, false),
-            AccountMeta::new(destination, false),
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new_readonly(token_program, false),
-        ];
+        let mut accounts = accounts::CancelProposal {
+            authority: authority.pubkey(),
+            treasury,
+            proposal,
+            proposer,
+        }
+        .to_account_metas(None);
+
+        // Treasury signers co-signing the cancellation
+        for co_signer in co_signers {
+            accounts.push(AccountMeta::new_readonly(co_signer.pubkey(), true));
+        }
 
-        let data = ExecuteProposalArgs {
+        let data = instruction::CancelProposal {
             name: name.to_string(),
             id,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::ExecuteProposal(data),
+            &data.data(),
             accounts,
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&executor.pubkey()));
-        transaction.sign(&[executor, authority], recent_blockhash);
-
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
+        let mut signers = vec![authority];
+        signers.extend_from_slice(co_signers);
+        transaction.sign(&signers, recent_blockhash);
         transaction
     }
 
+    // Emergency Pause
+    pub fn emergency_pause_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        authority_role: Option<Pubkey>,
+        name: &str,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::EmergencyPause {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+        };
+
+        let data = instruction::EmergencyPause {
+            name: name.to_string(),
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
+    // Resume Operations
+    pub fn resume_operations_ix_setup(
+        authority: &Keypair,
+        treasury: Pubkey,
+        name: &str,
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let accounts = accounts::ResumeOperations {
+            authority: authority.pubkey(),
+            treasury,
+        };
+
+        let data = instruction::ResumeOperations {
+            name: name.to_string(),
+        };
+
+        let instruction = Instruction::new_with_bytes(
+            treasury_management::ID,
+            &data.data(),
+            accounts.to_account_metas(None),
+        );
+
+        signed_transaction(instruction, authority, recent_blockhash)
+    }
+
     // Add Whitelist Recipient
     pub fn add_whitelist_recipient_ix_setup(
         authority: &Keypair,
         treasury: Pubkey,
+        authority_role: Option<Pubkey>,
         whitelist_entry: Pubkey,
         system_program: Pubkey,
         name: &str,
@@ -455,96 +2188,82 @@ pub mod treasury_management_ix_interface {
         label: &str,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new_readonly(treasury, false),
-            AccountMeta::new(whitelist_entry, false),
-            AccountMeta::new_readonly(system_program, false),
-        ];
+        let accounts = accounts::AddWhitelistRecipient {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            whitelist_entry,
+            system_program,
+        };
 
-        let data = AddWhitelistRecipientArgs {
+        let data = instruction::AddWhitelistRecipient {
             name: name.to_string(),
             recipient,
             label: label.to_string(),
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::AddWhitelistRecipient(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, authority, recent_blockhash)
     }
 
     // Remove Whitelist Recipient
     pub fn remove_whitelist_recipient_ix_setup(
         authority: &Keypair,
         treasury: Pubkey,
+        authority_role: Option<Pubkey>,
         whitelist_entry: Pubkey,
         name: &str,
         recipient: Pubkey,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new_readonly(authority.pubkey(), true),
-            AccountMeta::new_readonly(treasury, false),
-            AccountMeta::new(whitelist_entry, false),
-        ];
+        let accounts = accounts::RemoveWhitelistRecipient {
+            authority: authority.pubkey(),
+            treasury,
+            authority_role,
+            whitelist_entry,
+        };
 
-        let data = RemoveWhitelistRecipientArgs {
+        let data = instruction::RemoveWhitelistRecipient {
             name: name.to_string(),
             recipient,
         };
 
-        let instruction = Instruction::new_with_borsh(
+        let instruction = Instruction::new_with_bytes(
             treasury_management::ID,
-            &TreasuryManagementInstruction::RemoveWhitelistRecipient(data),
-            accounts,
+            &data.data(),
+            accounts.to_account_metas(None),
         );
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, authority, recent_blockhash)
     }
 }
 
 pub mod csl_spl_token_ix_interface {
     use super::*;
-    use solana_sdk::instruction::AccountMeta;
 
     pub fn transfer_ix_setup(
         authority: &Keypair,
         source: Pubkey,
         destination: Pubkey,
-        authority_pubkey: Pubkey,
         token_program: Pubkey,
         amount: u64,
         recent_blockhash: Hash,
     ) -> Transaction {
-        let accounts = vec![
-            AccountMeta::new(source, false),
-            AccountMeta::new(destination, false),
-            AccountMeta::new_readonly(authority_pubkey, true),
-        ];
-
-        let instruction = solana_sdk::spl_token::instruction::transfer(
+        let instruction = spl_token::instruction::transfer(
             &token_program,
             &source,
             &destination,
-            &authority_pubkey,
+            &authority.pubkey(),
             &[],
             amount,
         )
         .unwrap();
 
-        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
-        transaction.sign(&[authority], recent_blockhash);
-
-        transaction
+        signed_transaction(instruction, authority, recent_blockhash)
     }
-}
\ No newline at end of file
+}
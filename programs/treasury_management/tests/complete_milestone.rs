@@ -13,6 +13,11 @@ use {
     },
 };
 
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, milestone_payment_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, MilestonePayment};
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
+
 
 #[tokio::test]
 async fn complete_milestone_ix_success() {
@@ -51,6 +56,7 @@ async fn complete_milestone_ix_success() {
 	let authority_pubkey = authority_keypair.pubkey();
 	let token_mint_pubkey = token_mint_keypair.pubkey();
 	let source_pubkey = Pubkey::new_unique();
+	let escrow_vault_pubkey = Pubkey::new_unique();
 	let destination_pubkey = Pubkey::new_unique();
 
 	// EXECUTABLE PUBKEY
@@ -68,12 +74,20 @@ async fn complete_milestone_ix_success() {
 	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"milestone",
-			treasury_pubkey.as_ref(),
+			treasury_pda.as_ref(),
 			id.to_le_bytes().as_ref(),
 		],
 		&treasury_management::ID,
 	);
 
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		authority_pubkey,
@@ -92,9 +106,13 @@ async fn complete_milestone_ix_success() {
 	let ix = treasury_management_ix_interface::complete_milestone_ix_setup(
 		&authority_keypair,
 		treasury_pda,
+		None,
 		milestone_payment_pda,
+		authority_pubkey,
 		token_mint_pubkey,
 		source_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pubkey,
 		destination_pubkey,
 		csl_spl_token_v0_0_0_pubkey,
 		&name,
@@ -108,3 +126,333 @@ async fn complete_milestone_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn complete_milestone_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, authority_pubkey);
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::complete_milestone_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		authority_pubkey,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn complete_milestone_ix_releases_escrow_to_recipient() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 0);
+	// 50 more than the milestone pays, sent to the escrow by someone else.
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_050);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, authority_pubkey);
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::complete_milestone_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		authority_pubkey,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 1_000);
+
+	// The surplus goes back to the treasury and the escrow is closed.
+	let token_vault: TokenAccount = get_program_account(&mut banks_client, token_vault_pubkey).await;
+	assert_eq!(token_vault.amount, 50);
+	assert!(banks_client.get_account(escrow_vault_pda).await.unwrap().is_none());
+
+	let milestone_payment: MilestonePayment = get_program_account(&mut banks_client, milestone_payment_pda).await;
+	assert!(milestone_payment.is_completed);
+}
+
+#[tokio::test]
+async fn complete_milestone_ix_rejects_completed_milestone() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (milestone_payment_pda, _milestone_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"milestone_escrow",
+			milestone_payment_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, escrow_vault_pda, token_mint_pubkey, escrow_authority_pda, 1_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut milestone_payment = milestone_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey, authority_pubkey);
+	milestone_payment.is_completed = true;
+	add_program_account(&mut program_test, milestone_payment_pda, &milestone_payment, 374);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::complete_milestone_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		milestone_payment_pda,
+		authority_pubkey,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::MilestoneAlreadyCompleted);
+}
@@ -0,0 +1,179 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{
+	add_program_account, assert_treasury_error, get_program_account, treasury_config_fixture, treasury_fixture,
+};
+use treasury_management::{error::TreasuryManagementError, Proposal, ProposalKind};
+
+
+
+#[tokio::test]
+async fn create_config_proposal_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let proposer_pubkey = proposer_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		proposer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, proposer_pubkey), 566);
+
+	let config = treasury_config_fixture(vec![proposer_pubkey, Pubkey::new_unique()], 2);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::create_config_proposal_ix_setup(
+		&proposer_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		system_program_pubkey,
+		&name,
+		id,
+		"Add a second signer",
+		"Move to 2-of-2 control",
+		config.clone(),
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.kind, ProposalKind::ConfigChange);
+	assert_eq!(proposal.config, Some(config));
+}
+
+#[tokio::test]
+async fn create_config_proposal_ix_rejects_invalid_config() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let proposer_keypair = Keypair::new();
+
+	// PUBKEY
+	let proposer_pubkey = proposer_keypair.pubkey();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		proposer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, proposer_pubkey), 566);
+
+	// A threshold above the signer count could never be met.
+	let config = treasury_config_fixture(vec![proposer_pubkey], 2);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::create_config_proposal_ix_setup(
+		&proposer_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		system_program_pubkey,
+		&name,
+		id,
+		"Add a second signer",
+		"Move to 2-of-2 control",
+		config,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidSignatureThreshold);
+}
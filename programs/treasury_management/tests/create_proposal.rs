@@ -12,6 +12,10 @@ use {
     },
 };
 
+use common::{add_program_account, assert_treasury_error, treasury_fixture};
+use treasury_management::error::TreasuryManagementError;
+
+
 
 #[tokio::test]
 async fn create_proposal_ix_success() {
@@ -76,6 +80,7 @@ async fn create_proposal_ix_success() {
     let ix = treasury_management_ix_interface::create_proposal_ix_setup(
         &proposer_keypair,
         treasury_pda,
+        None,
         proposal_pda,
         system_program_pubkey,
         &name,
@@ -94,4 +99,85 @@ async fn create_proposal_ix_success() {
     // This will likely fail without a properly initialized treasury
     // In a complete test suite, you would first initialize the treasury
     assert!(result.is_err()); // Expecting error since treasury doesn't exist yet
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn create_proposal_ix_rejects_signer_without_permission() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name = "Permissioned Treasury".to_string();
+    let id: u64 = 1;
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let outsider_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let outsider_pubkey = outsider_keypair.pubkey();
+    let recipient_pubkey = Pubkey::new_unique();
+    let token_mint_pubkey = Pubkey::new_unique();
+
+    // EXECUTABLE PUBKEY
+    let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"proposal",
+            treasury_pda.as_ref(),
+            id.to_le_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        outsider_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::create_proposal_ix_setup(
+        &outsider_keypair,
+        treasury_pda,
+        None,
+        proposal_pda,
+        system_program_pubkey,
+        &name,
+        id,
+        "Fund audit",
+        "Pay the auditors",
+        1_000,
+        token_mint_pubkey,
+        recipient_pubkey,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
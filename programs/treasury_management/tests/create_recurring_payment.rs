@@ -9,10 +9,14 @@ use {
 	},
     solana_program_test::tokio,
     solana_sdk::{
-        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+        account::Account, clock::Clock, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
     },
 };
 
+use common::{add_program_account, assert_treasury_error, get_program_account, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, RecurringPayment, RecurringSchedule};
+
+
 
 #[tokio::test]
 async fn create_recurring_payment_ix_success() {
@@ -62,7 +66,7 @@ async fn create_recurring_payment_ix_success() {
 	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"recurring",
-			treasury_pubkey.as_ref(),
+			treasury_pda.as_ref(),
 			recipient.as_ref(),
 		],
 		&treasury_management::ID,
@@ -86,6 +90,7 @@ async fn create_recurring_payment_ix_success() {
 	let ix = treasury_management_ix_interface::create_recurring_payment_ix_setup(
 		&authority_keypair,
 		treasury_pda,
+		None,
 		recurring_payment_pda,
 		system_program_pubkey,
 		&name,
@@ -94,6 +99,259 @@ async fn create_recurring_payment_ix_success() {
 		amount,
 		interval,
 		category,
+		None,
+		None,
+		RecurringSchedule::Interval,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+}
+
+#[tokio::test]
+async fn create_recurring_payment_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::create_recurring_payment_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		system_program_pubkey,
+		&name,
+		recipient_pubkey,
+		token_mint_pubkey,
+		1_000,
+		86400,
+		0,
+		None,
+		None,
+		RecurringSchedule::Interval,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn create_recurring_payment_ix_rejects_zero_payment_cap() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::create_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		system_program_pubkey,
+		&name,
+		recipient_pubkey,
+		token_mint_pubkey,
+		1_000,
+		86400,
+		0,
+		Some(0),
+		None,
+		RecurringSchedule::Interval,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidRecurringSchedule);
+}
+
+#[tokio::test]
+async fn create_recurring_payment_ix_starts_quarterly_schedule_in_its_anchor_month() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let recent_blockhash = context.last_blockhash;
+
+	// 2027-02-20 12:00
+	let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+	clock.unix_timestamp = 1_803_124_800;
+	context.set_sysvar(&clock);
+	let banks_client = &mut context.banks_client;
+
+	let ix = treasury_management_ix_interface::create_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		system_program_pubkey,
+		&name,
+		recipient_pubkey,
+		token_mint_pubkey,
+		1_000,
+		86400,
+		0,
+		None,
+		None,
+		RecurringSchedule::Quarterly { day: 15, month: 1 },
 		recent_blockhash,
 	);
 
@@ -102,4 +360,88 @@ async fn create_recurring_payment_ix_success() {
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let recurring_payment: RecurringPayment = get_program_account(banks_client, recurring_payment_pda).await;
+	// 2027-04-15: January, April, July and October rather than every third
+	// month from February.
+	assert_eq!(recurring_payment.next_payment_date, 1_807_747_200);
+}
+
+#[tokio::test]
+async fn create_recurring_payment_ix_rejects_invalid_anchor_month() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::create_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		system_program_pubkey,
+		&name,
+		recipient_pubkey,
+		token_mint_pubkey,
+		1_000,
+		86400,
+		0,
+		None,
+		None,
+		RecurringSchedule::Yearly { day: 1, month: 13 },
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidRecurringSchedule);
 }
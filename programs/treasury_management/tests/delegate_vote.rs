@@ -0,0 +1,319 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, get_program_account, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, VoteDelegation};
+
+
+
+#[tokio::test]
+async fn delegate_vote_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let delegator_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let delegator_pubkey = delegator_keypair.pubkey();
+	let delegate_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (delegation_pda, _delegation_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_delegation",
+			treasury_pda.as_ref(),
+			delegator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		delegator_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, delegator_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::delegate_vote_ix_setup(
+		&delegator_keypair,
+		treasury_pda,
+		delegation_pda,
+		system_program::ID,
+		&name,
+		delegate_pubkey,
+		Some(4_000_000_000),
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let delegation: VoteDelegation = get_program_account(&mut banks_client, delegation_pda).await;
+	assert_eq!(delegation.treasury, treasury_pda);
+	assert_eq!(delegation.delegator, delegator_pubkey);
+	assert_eq!(delegation.delegate, delegate_pubkey);
+	assert_eq!(delegation.expires_at, Some(4_000_000_000));
+}
+
+#[tokio::test]
+async fn delegate_vote_ix_rejects_non_signer() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let delegator_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let delegator_pubkey = delegator_keypair.pubkey();
+	let delegate_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (delegation_pda, _delegation_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_delegation",
+			treasury_pda.as_ref(),
+			delegator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		delegator_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::delegate_vote_ix_setup(
+		&delegator_keypair,
+		treasury_pda,
+		delegation_pda,
+		system_program::ID,
+		&name,
+		delegate_pubkey,
+		None,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::NotTreasurySigner);
+}
+
+#[tokio::test]
+async fn delegate_vote_ix_rejects_past_expiry() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let delegator_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let delegator_pubkey = delegator_keypair.pubkey();
+	let delegate_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (delegation_pda, _delegation_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_delegation",
+			treasury_pda.as_ref(),
+			delegator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		delegator_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, delegator_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::delegate_vote_ix_setup(
+		&delegator_keypair,
+		treasury_pda,
+		delegation_pda,
+		system_program::ID,
+		&name,
+		delegate_pubkey,
+		Some(1),
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidDelegation);
+}
+
+#[tokio::test]
+async fn delegate_vote_ix_rejects_self_delegation() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let delegator_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let delegator_pubkey = delegator_keypair.pubkey();
+	let delegate_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (delegation_pda, _delegation_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_delegation",
+			treasury_pda.as_ref(),
+			delegator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		delegator_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, delegator_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::delegate_vote_ix_setup(
+		&delegator_keypair,
+		treasury_pda,
+		delegation_pda,
+		system_program::ID,
+		&name,
+		delegator_pubkey,
+		None,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidDelegation);
+}
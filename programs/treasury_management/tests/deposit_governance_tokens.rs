@@ -0,0 +1,347 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Treasury, VoterEscrow};
+use anchor_spl::token::TokenAccount;
+
+
+
+#[tokio::test]
+async fn deposit_governance_tokens_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let amount: u64 = 400;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let source_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			governance_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account(&mut program_test, governance_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, source_pubkey, governance_mint_pubkey, voter_pubkey, 1_000);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::deposit_governance_tokens_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		governance_mint_pubkey,
+		source_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		voter_escrow_pda,
+		token_program_pubkey,
+		system_program::ID,
+		&name,
+		amount,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let voter_escrow: VoterEscrow = get_program_account(&mut banks_client, voter_escrow_pda).await;
+	assert_eq!(voter_escrow.owner, voter_pubkey);
+	assert_eq!(voter_escrow.amount, amount);
+
+	let escrow_vault: TokenAccount = get_program_account(&mut banks_client, escrow_vault_pda).await;
+	assert_eq!(escrow_vault.amount, amount);
+	assert_eq!(escrow_vault.owner, escrow_authority_pda);
+
+	let treasury: Treasury = get_program_account(&mut banks_client, treasury_pda).await;
+	assert_eq!(treasury.governance_deposits, amount);
+}
+
+#[tokio::test]
+async fn deposit_governance_tokens_ix_rejects_treasury_without_governance_mint() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Multisig Treasury".to_string();
+	let amount: u64 = 400;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let source_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			governance_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, governance_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, source_pubkey, governance_mint_pubkey, voter_pubkey, 1_000);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::deposit_governance_tokens_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		governance_mint_pubkey,
+		source_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		voter_escrow_pda,
+		token_program_pubkey,
+		system_program::ID,
+		&name,
+		amount,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::GovernanceNotEnabled);
+}
+
+#[tokio::test]
+async fn deposit_governance_tokens_ix_opens_new_escrow_after_mint_change() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let amount: u64 = 400;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let old_mint_pubkey = Pubkey::new_unique();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let source_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (old_escrow_vault_pda, _old_escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			old_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			governance_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// The escrow of the previous governance mint is left behind, emptied.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account(&mut program_test, old_mint_pubkey, authority_pubkey, 6);
+	add_mint_account(&mut program_test, governance_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, old_escrow_vault_pda, old_mint_pubkey, escrow_authority_pda, 0);
+	add_token_account(&mut program_test, source_pubkey, governance_mint_pubkey, voter_pubkey, 1_000);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::deposit_governance_tokens_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		governance_mint_pubkey,
+		source_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		voter_escrow_pda,
+		token_program_pubkey,
+		system_program::ID,
+		&name,
+		amount,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let escrow_vault: TokenAccount = get_program_account(&mut banks_client, escrow_vault_pda).await;
+	assert_eq!(escrow_vault.mint, governance_mint_pubkey);
+	assert_eq!(escrow_vault.amount, amount);
+}
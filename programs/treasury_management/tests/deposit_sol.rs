@@ -35,7 +35,7 @@ async fn deposit_sol_ix_success() {
 
 	// DATA
 	let name: String = Default::default();
-	let amount: u64 = Default::default();
+	let amount: u64 = 1_000_000_000;
 
 	// KEYPAIR
 	let depositor_keypair = Keypair::new();
@@ -43,6 +43,9 @@ async fn deposit_sol_ix_success() {
 	// PUBKEY
 	let depositor_pubkey = depositor_keypair.pubkey();
 
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
 	// PDA
 	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
 		&[
@@ -52,6 +55,14 @@ async fn deposit_sol_ix_success() {
 		&treasury_management::ID,
 	);
 
+	let (sol_vault_pda, _sol_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"sol_vault",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		depositor_pubkey,
@@ -70,6 +81,8 @@ async fn deposit_sol_ix_success() {
 	let ix = treasury_management_ix_interface::deposit_sol_ix_setup(
 		&depositor_keypair,
 		treasury_pda,
+		sol_vault_pda,
+		system_program_pubkey,
 		&name,
 		amount,
 		recent_blockhash,
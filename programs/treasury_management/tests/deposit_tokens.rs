@@ -99,7 +99,6 @@ async fn deposit_tokens_ix_success() {
 		token_mint_pubkey,
 		source_pubkey,
 		destination_pubkey,
-		&authority_keypair,
 		csl_spl_token_v0_0_0_pubkey,
 		&name,
 		amount,
@@ -0,0 +1,203 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{
+	add_program_account, assert_treasury_error, get_program_account, proposal_fixture, treasury_config_fixture,
+	treasury_fixture,
+};
+use treasury_management::{
+	error::TreasuryManagementError, Proposal, ProposalKind, ProposalStatus, ProposalVote, Treasury,
+	TreasuryConfig,
+};
+
+
+
+#[tokio::test]
+async fn execute_config_proposal_ix_applies_config() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let executor_keypair = signer_keypair.insecure_clone();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let executor_pubkey = executor_keypair.pubkey();
+	let new_signer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		executor_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// Rotate the second signer out for a new key.
+	let config = treasury_config_fixture(vec![authority_pubkey, new_signer_pubkey], 2);
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, Pubkey::default(), Pubkey::default());
+	proposal.kind = ProposalKind::ConfigChange;
+	proposal.status = ProposalStatus::Approved;
+	proposal.votes = vec![
+		ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey },
+		ProposalVote { voter: signer_pubkey, vote_for: true, weight: 1, cast_by: signer_pubkey },
+	];
+	proposal.config = Some(config.clone());
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504 + TreasuryConfig::SPACE);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_config_proposal_ix_setup(
+		&executor_keypair,
+		treasury_pda,
+		proposal_pda,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let treasury: Treasury = get_program_account(&mut banks_client, treasury_pda).await;
+	assert_eq!(treasury.signers, config.signers);
+	assert_eq!(treasury.voting_period, config.voting_period);
+	assert_eq!(treasury.execution_delay, config.execution_delay);
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.status, ProposalStatus::Executed);
+}
+
+#[tokio::test]
+async fn execute_config_proposal_ix_rejects_non_signer() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let signer_keypair = Keypair::new();
+	let executor_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let signer_pubkey = signer_keypair.pubkey();
+	let executor_pubkey = executor_keypair.pubkey();
+	let new_signer_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		executor_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, signer_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// Rotate the second signer out for a new key.
+	let config = treasury_config_fixture(vec![authority_pubkey, new_signer_pubkey], 2);
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, Pubkey::default(), Pubkey::default());
+	proposal.kind = ProposalKind::ConfigChange;
+	proposal.status = ProposalStatus::Approved;
+	proposal.config = Some(config.clone());
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504 + TreasuryConfig::SPACE);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_config_proposal_ix_setup(
+		&executor_keypair,
+		treasury_pda,
+		proposal_pda,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::NotTreasurySigner);
+}
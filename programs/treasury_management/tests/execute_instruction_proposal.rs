@@ -0,0 +1,348 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use anchor_spl::{
+	associated_token::get_associated_token_address,
+	token::{spl_token, TokenAccount},
+};
+use common::{
+	add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account,
+	proposal_fixture, treasury_fixture,
+};
+use treasury_management::{
+	error::TreasuryManagementError, Proposal, ProposalAccountMeta, ProposalInstruction, ProposalKind,
+	ProposalStatus, ProposalVote,
+};
+
+
+
+fn token_transfer_instruction(
+	token_vault: Pubkey,
+	destination: Pubkey,
+	treasury: Pubkey,
+	amount: u64,
+) -> ProposalInstruction {
+	let instruction = spl_token::instruction::transfer(
+		&spl_token::ID,
+		&token_vault,
+		&destination,
+		&treasury,
+		&[],
+		amount,
+	)
+	.unwrap();
+
+	ProposalInstruction {
+		program_id: instruction.program_id,
+		accounts: instruction.accounts
+			.iter()
+			.map(|meta| ProposalAccountMeta {
+				pubkey: meta.pubkey,
+				is_signer: meta.is_signer,
+				is_writable: meta.is_writable,
+			})
+			.collect(),
+		data: instruction.data,
+	}
+}
+
+#[tokio::test]
+async fn execute_instruction_proposal_ix_invokes_instructions_as_treasury() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+
+	let instructions = vec![token_transfer_instruction(token_vault_pubkey, destination_pubkey, treasury_pda, 500)];
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, Pubkey::default(), Pubkey::default());
+	proposal.kind = ProposalKind::Instructions;
+	proposal.status = ProposalStatus::Approved;
+	proposal.votes = vec![ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey }];
+	proposal.instructions = instructions.clone();
+	add_program_account(
+		&mut program_test,
+		proposal_pda,
+		&proposal,
+		1504 + ProposalInstruction::space(&instructions),
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_instruction_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		vec![
+			AccountMeta::new_readonly(spl_token::ID, false),
+			AccountMeta::new(token_vault_pubkey, false),
+			AccountMeta::new(destination_pubkey, false),
+		],
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 500);
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.status, ProposalStatus::Executed);
+}
+
+#[tokio::test]
+async fn execute_instruction_proposal_ix_rejects_transfer_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_instruction_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		vec![],
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalKindMismatch);
+}
+
+#[tokio::test]
+async fn execute_instruction_proposal_ix_cannot_move_governance_escrow() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			governance_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account(&mut program_test, governance_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, escrow_vault_pda, governance_mint_pubkey, escrow_authority_pda, 400);
+	add_token_account(&mut program_test, destination_pubkey, governance_mint_pubkey, authority_pubkey, 0);
+
+	let instructions = vec![token_transfer_instruction(escrow_vault_pda, destination_pubkey, escrow_authority_pda, 400)];
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, Pubkey::default(), Pubkey::default());
+	proposal.kind = ProposalKind::Instructions;
+	proposal.status = ProposalStatus::Approved;
+	proposal.votes = vec![ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey }];
+	proposal.instructions = instructions.clone();
+	add_program_account(
+		&mut program_test,
+		proposal_pda,
+		&proposal,
+		1504 + ProposalInstruction::space(&instructions),
+	);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_instruction_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		vec![
+			AccountMeta::new_readonly(spl_token::ID, false),
+			AccountMeta::new(escrow_vault_pda, false),
+			AccountMeta::new(destination_pubkey, false),
+			AccountMeta::new_readonly(escrow_authority_pda, false),
+		],
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	// The treasury's seeds cannot sign for the escrow authority.
+	assert!(result.is_err());
+
+	let escrow_vault: TokenAccount = get_program_account(&mut banks_client, escrow_vault_pda).await;
+	assert_eq!(escrow_vault.amount, 400);
+}
@@ -13,6 +13,11 @@ use {
     },
 };
 
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, proposal_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Proposal, ProposalStatus, ProposalVote};
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
+
 
 #[tokio::test]
 async fn execute_proposal_ix_success() {
@@ -70,7 +75,7 @@ async fn execute_proposal_ix_success() {
 	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"proposal",
-			treasury_pubkey.as_ref(),
+			treasury_pda.as_ref(),
 			id.to_le_bytes().as_ref(),
 		],
 		&treasury_management::ID,
@@ -105,11 +110,11 @@ async fn execute_proposal_ix_success() {
 	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
 		&executor_keypair,
 		treasury_pda,
+		None,
 		proposal_pda,
 		token_mint_pubkey,
 		source_pubkey,
 		destination_pubkey,
-		&authority_keypair,
 		csl_spl_token_v0_0_0_pubkey,
 		&name,
 		id,
@@ -122,3 +127,532 @@ async fn execute_proposal_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_unapproved_proposal() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalNotApproved);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_transfers_approved_amount() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	proposal.votes = vec![ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey }];
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 1_000);
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.status, ProposalStatus::Executed);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_after_execution_deadline() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	proposal.voting_deadline = 0;
+	proposal.execution_deadline = 0;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalExpired);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_during_timelock() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	proposal.executable_at = i64::MAX;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalTimelocked);
+}
+
+#[tokio::test]
+async fn execute_proposal_ix_rejects_approval_from_removed_signer() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.status = ProposalStatus::Approved;
+	// Approved by a signer that a config change has since removed.
+	let removed_signer_pubkey = Pubkey::new_unique();
+	proposal.votes = vec![ProposalVote { voter: removed_signer_pubkey, vote_for: true, weight: 1, cast_by: removed_signer_pubkey }];
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		proposal_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalApprovalLapsed);
+}
@@ -13,6 +13,11 @@ use {
     },
 };
 
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, recurring_payment_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, RecurringPayment, RecurringSchedule, MAX_RECURRING_CATCH_UP};
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
+
 
 #[tokio::test]
 async fn execute_recurring_payment_ix_success() {
@@ -70,7 +75,7 @@ async fn execute_recurring_payment_ix_success() {
 	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"recurring",
-			treasury_pubkey.as_ref(),
+			treasury_pda.as_ref(),
 			recipient.as_ref(),
 		],
 		&treasury_management::ID,
@@ -105,14 +110,300 @@ async fn execute_recurring_payment_ix_success() {
 	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
 		&executor_keypair,
 		treasury_pda,
+		None,
 		recurring_payment_pda,
 		token_mint_pubkey,
 		source_pubkey,
 		destination_pubkey,
-		&authority_keypair,
 		csl_spl_token_v0_0_0_pubkey,
 		&name,
 		recipient,
+		1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let recurring_payment = RecurringPayment {
+		treasury: treasury_pda,
+		recipient: recipient_pubkey,
+		token_mint: token_mint_pubkey,
+		amount: 1_000,
+		interval: 86400,
+		next_payment_date: 0,
+		is_active: true,
+		created_by: authority_pubkey,
+		category: 0,
+		payments_made: 0,
+		max_payments: None,
+		end_date: None,
+		schedule: RecurringSchedule::Interval,
+		is_paused: false,
+		destination: None,
+	};
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_catches_up_on_missed_intervals() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		3,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 3_000);
+
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert_eq!(recurring_payment.payments_made, 3);
+	assert_eq!(recurring_payment.next_payment_date, 300);
+	assert!(recurring_payment.is_active);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_follows_monthly_calendar() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	// 2024-01-31, so later installments land on Feb 29 and Mar 31.
+	recurring_payment.schedule = RecurringSchedule::Monthly { day: 31 };
+	recurring_payment.next_payment_date = 1_706_659_200;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		3,
 		recent_blockhash,
 	);
 
@@ -121,4 +412,826 @@ async fn execute_recurring_payment_ix_success() {
 	// ASSERTIONS
 	assert!(result.is_ok());
 
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 3_000);
+
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert_eq!(recurring_payment.payments_made, 3);
+	// 2024-04-30
+	assert_eq!(recurring_payment.next_payment_date, 1_714_435_200);
+	assert!(recurring_payment.is_active);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_follows_last_business_day() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	// Friday 2024-03-29, since March 31 is a Sunday.
+	recurring_payment.schedule = RecurringSchedule::LastBusinessDay;
+	recurring_payment.next_payment_date = 1_711_670_400;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		3,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 3_000);
+
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert_eq!(recurring_payment.payments_made, 3);
+	// Friday 2024-06-28, after 2024-04-30 and 2024-05-31.
+	assert_eq!(recurring_payment.next_payment_date, 1_719_532_800);
+	assert!(recurring_payment.is_active);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_follows_quarterly_calendar() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	// 2024-01-31
+	recurring_payment.schedule = RecurringSchedule::Quarterly { day: 31, month: 1 };
+	recurring_payment.next_payment_date = 1_706_659_200;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		2,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 2_000);
+
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert_eq!(recurring_payment.payments_made, 2);
+	// 2024-07-31, after 2024-04-30.
+	assert_eq!(recurring_payment.next_payment_date, 1_722_384_000);
+	assert!(recurring_payment.is_active);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_deactivates_after_max_payments() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.max_payments = Some(2);
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		5,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 2_000);
+
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert_eq!(recurring_payment.payments_made, 2);
+	assert!(!recurring_payment.is_active);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_deactivates_after_end_date() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.end_date = Some(150);
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		5,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 2_000);
+
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert_eq!(recurring_payment.next_payment_date, 200);
+	assert!(!recurring_payment.is_active);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_rejects_payment_not_due() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.next_payment_date = i64::MAX;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::RecurringPaymentNotDue);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_rejects_paused_payment() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.is_paused = true;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::RecurringPaymentPaused);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_pays_into_configured_destination() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+	let custodian_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	// The configured destination need not belong to the recipient.
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, custodian_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.destination = Some(destination_pubkey);
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, 1_000);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_rejects_recipient_account_when_destination_is_set() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.destination = Some(Pubkey::new_unique());
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidRecurringDestination);
+}
+
+#[tokio::test]
+async fn execute_recurring_payment_ix_rejects_unbounded_catch_up() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		recipient_pubkey,
+		MAX_RECURRING_CATCH_UP + 1,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InvalidRecurringCatchUp);
 }
@@ -13,6 +13,11 @@ use {
     },
 };
 
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, PaymentStream};
+use anchor_spl::associated_token::get_associated_token_address;
+
+
 
 #[tokio::test]
 async fn execute_stream_payment_ix_success() {
@@ -41,7 +46,7 @@ async fn execute_stream_payment_ix_success() {
 
 	// DATA
 	let name: String = Default::default();
-	let recipient: Pubkey = Pubkey::default();
+	let stream_id: u64 = Default::default();
 
 	// KEYPAIR
 	let executor_keypair = Keypair::new();
@@ -70,8 +75,8 @@ async fn execute_stream_payment_ix_success() {
 	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
 		&[
 			b"payment_stream",
-			treasury_pubkey.as_ref(),
-			recipient.as_ref(),
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
 		],
 		&treasury_management::ID,
 	);
@@ -105,14 +110,14 @@ async fn execute_stream_payment_ix_success() {
 	let ix = treasury_management_ix_interface::execute_stream_payment_ix_setup(
 		&executor_keypair,
 		treasury_pda,
+		None,
 		payment_stream_pda,
 		token_mint_pubkey,
 		source_pubkey,
 		destination_pubkey,
-		&authority_keypair,
 		csl_spl_token_v0_0_0_pubkey,
 		&name,
-		recipient,
+		stream_id,
 		recent_blockhash,
 	);
 
@@ -122,3 +127,111 @@ async fn execute_stream_payment_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn execute_stream_payment_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let token_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let token_vault_pubkey = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	add_mint_account(&mut program_test, token_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, token_vault_pubkey, token_mint_pubkey, treasury_pda, 1_000_000);
+	add_token_account(&mut program_test, destination_pubkey, token_mint_pubkey, recipient_pubkey, 0);
+	let payment_stream = PaymentStream {
+		treasury: treasury_pda,
+		id: stream_id,
+		recipient: recipient_pubkey,
+		token_mint: token_mint_pubkey,
+		amount_per_period: 1_000,
+		period_duration: 86400,
+		start_time: 0,
+		end_time: i64::MAX,
+		cliff_time: 0,
+		accrued: 0,
+		accrued_until: 0,
+		budget: u64::MAX,
+		is_paused: false,
+		last_payment_time: 0,
+		total_paid: 0,
+		is_active: true,
+		category: 0,
+		created_by: authority_pubkey,
+		transfer_requires_approval: false,
+		charge_period: 0,
+	};
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::execute_stream_payment_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		payment_stream_pda,
+		token_mint_pubkey,
+		token_vault_pubkey,
+		destination_pubkey,
+		token_program_pubkey,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
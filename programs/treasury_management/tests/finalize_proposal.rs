@@ -0,0 +1,353 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_mint_account_with_supply, add_program_account, assert_treasury_error, get_program_account, proposal_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Proposal, ProposalStatus};
+
+
+
+#[tokio::test]
+async fn finalize_proposal_ix_approves_with_quorum_and_majority() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// 10% quorum and 50% approval over a supply of 1,000 tokens.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account_with_supply(&mut program_test, governance_mint_pubkey, authority_pubkey, 6, 1_000);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.voting_deadline = 0;
+	proposal.votes_for = 90;
+	proposal.votes_against = 30;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::finalize_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		proposal_pda,
+		governance_mint_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.status, ProposalStatus::Approved);
+	assert_eq!(proposal.executable_at, proposal.approved_at + 86_400);
+}
+
+#[tokio::test]
+async fn finalize_proposal_ix_rejects_below_quorum() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// 10% quorum and 50% approval over a supply of 1,000 tokens.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account_with_supply(&mut program_test, governance_mint_pubkey, authority_pubkey, 6, 1_000);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.voting_deadline = 0;
+	proposal.votes_for = 60;
+	proposal.votes_against = 0;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::finalize_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		proposal_pda,
+		governance_mint_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[tokio::test]
+async fn finalize_proposal_ix_rejects_without_majority() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// 10% quorum and 50% approval over a supply of 1,000 tokens.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account_with_supply(&mut program_test, governance_mint_pubkey, authority_pubkey, 6, 1_000);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.voting_deadline = 0;
+	proposal.votes_for = 100;
+	proposal.votes_against = 150;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::finalize_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		proposal_pda,
+		governance_mint_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[tokio::test]
+async fn finalize_proposal_ix_rejects_while_voting_is_open() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// 10% quorum and 50% approval over a supply of 1,000 tokens.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_mint_account_with_supply(&mut program_test, governance_mint_pubkey, authority_pubkey, 6, 1_000);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.votes_for = 900;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::finalize_proposal_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		proposal_pda,
+		governance_mint_pubkey,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ProposalVotingOpen);
+}
@@ -44,6 +44,7 @@ async fn initialize_treasury_ix_success() {
 
     // Create signers array
     let signers = vec![signer1_pubkey, signer2_pubkey, signer3_pubkey];
+    let signer_weights: Vec<u8> = vec![1, 1, 1];
 
     // EXECUTABLE PUBKEY
     let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
@@ -78,6 +79,7 @@ async fn initialize_treasury_ix_success() {
         system_program_pubkey,
         &name,
         &signers,
+        &signer_weights,
         threshold,
         admin_limit,
         treasurer_limit,
@@ -92,4 +94,253 @@ async fn initialize_treasury_ix_success() {
 
     // ASSERTIONS
     assert!(result.is_ok());
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn initialize_treasury_ix_rejects_threshold_above_total_weight() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name = "Test Treasury".to_string();
+    let threshold: u8 = 4;
+    let admin_limit: u64 = 1_000_000_000;
+    let treasurer_limit: u64 = 500_000_000;
+    let contributor_limit: u64 = 100_000_000;
+    let reset_period: u64 = 604800; // Weekly
+    let auto_stake: bool = true;
+    let stake_target_percentage: u8 = 50;
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let signer1_keypair = Keypair::new();
+    let signer2_keypair = Keypair::new();
+    let signer3_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let signer1_pubkey = signer1_keypair.pubkey();
+    let signer2_pubkey = signer2_keypair.pubkey();
+    let signer3_pubkey = signer3_keypair.pubkey();
+
+    // Create signers array
+    let signers = vec![signer1_pubkey, signer2_pubkey, signer3_pubkey];
+    let signer_weights: Vec<u8> = vec![1, 1, 1];
+
+    // EXECUTABLE PUBKEY
+    let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        authority_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::initialize_treasury_ix_setup(
+        &authority_keypair,
+        treasury_pda,
+        system_program_pubkey,
+        &name,
+        &signers,
+        &signer_weights,
+        threshold,
+        admin_limit,
+        treasurer_limit,
+        contributor_limit,
+        reset_period,
+        auto_stake,
+        stake_target_percentage,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn initialize_treasury_ix_accepts_threshold_reached_by_weight() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name = "Test Treasury".to_string();
+    let threshold: u8 = 4;
+    let admin_limit: u64 = 1_000_000_000;
+    let treasurer_limit: u64 = 500_000_000;
+    let contributor_limit: u64 = 100_000_000;
+    let reset_period: u64 = 604800; // Weekly
+    let auto_stake: bool = true;
+    let stake_target_percentage: u8 = 50;
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let signer1_keypair = Keypair::new();
+    let signer2_keypair = Keypair::new();
+    let signer3_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let signer1_pubkey = signer1_keypair.pubkey();
+    let signer2_pubkey = signer2_keypair.pubkey();
+    let signer3_pubkey = signer3_keypair.pubkey();
+
+    // Create signers array
+    let signers = vec![signer1_pubkey, signer2_pubkey, signer3_pubkey];
+    let signer_weights: Vec<u8> = vec![2, 1, 1];
+
+    // EXECUTABLE PUBKEY
+    let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        authority_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::initialize_treasury_ix_setup(
+        &authority_keypair,
+        treasury_pda,
+        system_program_pubkey,
+        &name,
+        &signers,
+        &signer_weights,
+        threshold,
+        admin_limit,
+        treasurer_limit,
+        contributor_limit,
+        reset_period,
+        auto_stake,
+        stake_target_percentage,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn initialize_treasury_ix_rejects_mismatched_signer_weights() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name = "Test Treasury".to_string();
+    let threshold: u8 = 2;
+    let admin_limit: u64 = 1_000_000_000;
+    let treasurer_limit: u64 = 500_000_000;
+    let contributor_limit: u64 = 100_000_000;
+    let reset_period: u64 = 604800; // Weekly
+    let auto_stake: bool = true;
+    let stake_target_percentage: u8 = 50;
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let signer1_keypair = Keypair::new();
+    let signer2_keypair = Keypair::new();
+    let signer3_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let signer1_pubkey = signer1_keypair.pubkey();
+    let signer2_pubkey = signer2_keypair.pubkey();
+    let signer3_pubkey = signer3_keypair.pubkey();
+
+    // Create signers array
+    let signers = vec![signer1_pubkey, signer2_pubkey, signer3_pubkey];
+    let signer_weights: Vec<u8> = vec![1, 1];
+
+    // EXECUTABLE PUBKEY
+    let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        authority_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::initialize_treasury_ix_setup(
+        &authority_keypair,
+        treasury_pda,
+        system_program_pubkey,
+        &name,
+        &signers,
+        &signer_weights,
+        threshold,
+        admin_limit,
+        treasurer_limit,
+        contributor_limit,
+        reset_period,
+        auto_stake,
+        stake_target_percentage,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert!(result.is_err());
+}
@@ -12,6 +12,10 @@ use {
     },
 };
 
+use common::{add_mint_account, add_token_account, get_program_account};
+use treasury_management::MilestonePayment;
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
 
 #[tokio::test]
 async fn milestone_payment_flow_test() {
@@ -53,11 +57,11 @@ async fn milestone_payment_flow_test() {
     let token_mint_pubkey = token_mint_keypair.pubkey();
     
     // Token accounts
-    let source_token_account = Pubkey::new_unique();
     let destination_token_account = Pubkey::new_unique();
 
     // Create signers array
     let signers = vec![admin_pubkey];
+    let signer_weights: Vec<u8> = vec![1];
 
     // EXECUTABLE PUBKEY
     let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
@@ -81,6 +85,24 @@ async fn milestone_payment_flow_test() {
         &treasury_management::ID,
     );
 
+    let token_vault = get_associated_token_address(&treasury_pda, &token_mint_pubkey);
+
+    let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"escrow_authority",
+            treasury_pda.as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"milestone_escrow",
+            milestone_payment_pda.as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
     // ACCOUNT PROGRAM TEST SETUP
     program_test.add_account(
         admin_pubkey,
@@ -104,6 +126,10 @@ async fn milestone_payment_flow_test() {
         },
     );
 
+    add_mint_account(&mut program_test, token_mint_pubkey, admin_pubkey, 9);
+    add_token_account(&mut program_test, token_vault, token_mint_pubkey, treasury_pda, milestone_amount);
+    add_token_account(&mut program_test, destination_token_account, token_mint_pubkey, recipient_pubkey, 0);
+
     // INSTRUCTIONS
     let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
@@ -114,6 +140,7 @@ async fn milestone_payment_flow_test() {
         system_program_pubkey,
         &name,
         &signers,
+        &signer_weights,
         threshold,
         admin_limit,
         treasurer_limit,
@@ -131,12 +158,17 @@ async fn milestone_payment_flow_test() {
     let ix_create_milestone = treasury_management_ix_interface::create_milestone_payment_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
         milestone_payment_pda,
+        token_mint_pubkey,
+        token_vault,
+        escrow_authority_pda,
+        escrow_vault_pda,
+        token_program_pubkey,
         system_program_pubkey,
         &name,
         milestone_id,
         recipient_pubkey,
-        token_mint_pubkey,
         milestone_amount,
         &milestone_description,
         milestone_category,
@@ -146,13 +178,23 @@ async fn milestone_payment_flow_test() {
     let result = banks_client.process_transaction(ix_create_milestone).await;
     assert!(result.is_ok(), "Failed to create milestone payment: {:?}", result);
 
+    // The whole amount is committed to the milestone's escrow up front.
+    let escrow: TokenAccount = get_program_account(&mut banks_client, escrow_vault_pda).await;
+    assert_eq!(escrow.amount, milestone_amount);
+    let vault: TokenAccount = get_program_account(&mut banks_client, token_vault).await;
+    assert_eq!(vault.amount, 0);
+
     // Step 3: Complete Milestone
     let ix_complete_milestone = treasury_management_ix_interface::complete_milestone_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
         milestone_payment_pda,
+        admin_pubkey,
         token_mint_pubkey,
-        source_token_account,
+        token_vault,
+        escrow_authority_pda,
+        escrow_vault_pda,
         destination_token_account,
         token_program_pubkey,
         &name,
@@ -160,16 +202,13 @@ async fn milestone_payment_flow_test() {
         recent_blockhash,
     );
 
-    // This will likely fail in the test environment without proper token account setup
-    // In a real environment with properly set up token accounts, this would work
     let result = banks_client.process_transaction(ix_complete_milestone).await;
-    assert!(result.is_err(), "Expected error due to missing token accounts");
-    
-    // In a complete test, we would:
-    // 1. Create the token mint
-    // 2. Create source and destination token accounts
-    // 3. Fund the source token account
-    // 4. Then complete the milestone
-    
-    // For this test, we're just verifying the instruction structure is correct
-}
\ No newline at end of file
+    assert!(result.is_ok(), "Failed to complete milestone: {:?}", result);
+
+    let destination: TokenAccount = get_program_account(&mut banks_client, destination_token_account).await;
+    assert_eq!(destination.amount, milestone_amount);
+    assert!(banks_client.get_account(escrow_vault_pda).await.unwrap().is_none());
+
+    let milestone: MilestonePayment = get_program_account(&mut banks_client, milestone_payment_pda).await;
+    assert!(milestone.is_completed);
+}
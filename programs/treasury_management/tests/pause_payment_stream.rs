@@ -0,0 +1,258 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, get_program_account, payment_stream_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, PaymentStream};
+
+
+
+#[tokio::test]
+async fn pause_payment_stream_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	// One token per second from the epoch, with no end in sight.
+	payment_stream.amount_per_period = 1;
+	payment_stream.period_duration = 1;
+	payment_stream.end_time = i64::MAX;
+	payment_stream.budget = u64::MAX;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::pause_payment_stream_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		payment_stream_pda,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let clock: Clock = banks_client.get_sysvar().await.unwrap();
+	let payment_stream: PaymentStream = get_program_account(&mut banks_client, payment_stream_pda).await;
+	assert!(payment_stream.is_paused);
+	assert_eq!(payment_stream.accrued, clock.unix_timestamp as u64);
+	assert_eq!(payment_stream.accrued_until, clock.unix_timestamp);
+	assert_eq!(payment_stream.accrued_amount(i64::MAX).unwrap(), payment_stream.accrued);
+}
+
+#[tokio::test]
+async fn pause_payment_stream_ix_rejects_paused_stream() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.is_paused = true;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::pause_payment_stream_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		payment_stream_pda,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::PaymentStreamPaused);
+}
+
+#[tokio::test]
+async fn pause_payment_stream_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::pause_payment_stream_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		payment_stream_pda,
+		&name,
+		stream_id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
@@ -28,6 +28,7 @@ async fn payment_stream_flow_test() {
 
     // DATA
     let name = "Stream Treasury".to_string();
+    let stream_id: u64 = 0;
     let threshold: u8 = 1;
     let admin_limit: u64 = 1_000_000_000;
     let treasurer_limit: u64 = 500_000_000;
@@ -61,6 +62,7 @@ async fn payment_stream_flow_test() {
 
     // Create signers array
     let signers = vec![admin_pubkey];
+    let signer_weights: Vec<u8> = vec![1];
 
     // EXECUTABLE PUBKEY
     let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
@@ -79,7 +81,7 @@ async fn payment_stream_flow_test() {
         &[
             b"payment_stream",
             treasury_pda.as_ref(),
-            recipient_pubkey.as_ref(),
+            stream_id.to_le_bytes().as_ref(),
         ],
         &treasury_management::ID,
     );
@@ -128,6 +130,7 @@ async fn payment_stream_flow_test() {
         system_program_pubkey,
         &name,
         &signers,
+        &signer_weights,
         threshold,
         admin_limit,
         treasurer_limit,
@@ -145,16 +148,20 @@ async fn payment_stream_flow_test() {
     let ix_create_stream = treasury_management_ix_interface::create_payment_stream_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
         payment_stream_pda,
         system_program_pubkey,
         &name,
+        stream_id,
         recipient_pubkey,
         token_mint_pubkey,
         amount_per_period,
         period_duration,
         start_time,
         end_time,
+        None,
         category,
+        false,
         recent_blockhash,
     );
 
@@ -165,13 +172,14 @@ async fn payment_stream_flow_test() {
     let ix_execute_stream = treasury_management_ix_interface::execute_stream_payment_ix_setup(
         &executor_keypair,
         treasury_pda,
+        None,
         payment_stream_pda,
         token_mint_pubkey,
         source_token_account,
         destination_token_account,
         token_program_pubkey,
         &name,
-        recipient_pubkey,
+        stream_id,
         recent_blockhash,
     );
 
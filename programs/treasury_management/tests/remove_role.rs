@@ -13,6 +13,10 @@ use {
     },
 };
 
+use common::{add_program_account, assert_treasury_error, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Role};
+
+
 
 #[tokio::test]
 async fn remove_role_ix_success() {
@@ -52,6 +56,15 @@ async fn remove_role_ix_success() {
 		&treasury_management::ID,
 	);
 
+	let (role_pda, _role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			user.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		authority_pubkey,
@@ -70,6 +83,8 @@ async fn remove_role_ix_success() {
 	let ix = treasury_management_ix_interface::remove_role_ix_setup(
 		&authority_keypair,
 		treasury_pda,
+		None,
+		role_pda,
 		&name,
 		user,
 		recent_blockhash,
@@ -81,3 +96,87 @@ async fn remove_role_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn remove_role_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let user_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (role_pda, _role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			user_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let role = Role {
+		treasury: treasury_pda,
+		user: user_pubkey,
+		role_type: 3,
+		can_execute_payments: false,
+		can_create_streams: false,
+		can_manage_roles: false,
+		can_view_treasury: true,
+		can_propose: false,
+		can_vote: false,
+		spending_limit_used: 0,
+		last_limit_reset: 0,
+	};
+	add_program_account(&mut program_test, role_pda, &role, 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::remove_role_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		role_pda,
+		&name,
+		user_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
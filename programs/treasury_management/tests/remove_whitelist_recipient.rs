@@ -12,6 +12,10 @@ use {
     },
 };
 
+use common::{add_program_account, assert_treasury_error, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, WhitelistedRecipient};
+
+
 
 #[tokio::test]
 async fn remove_whitelist_recipient_ix_success() {
@@ -66,6 +70,7 @@ async fn remove_whitelist_recipient_ix_success() {
     let ix = treasury_management_ix_interface::remove_whitelist_recipient_ix_setup(
         &authority_keypair,
         treasury_pda,
+        None,
         whitelist_entry_pda,
         &name,
         recipient,
@@ -78,4 +83,82 @@ async fn remove_whitelist_recipient_ix_success() {
     // This will likely fail without a properly initialized treasury and whitelist entry
     // In a complete test suite, you would first initialize the treasury and add the whitelist entry
     assert!(result.is_err()); // Expecting error since treasury and whitelist entry don't exist yet
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn remove_whitelist_recipient_ix_rejects_signer_without_permission() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name = "Permissioned Treasury".to_string();
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let outsider_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let outsider_pubkey = outsider_keypair.pubkey();
+    let recipient_pubkey = Pubkey::new_unique();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (whitelist_entry_pda, _whitelist_entry_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"whitelist",
+            treasury_pda.as_ref(),
+            recipient_pubkey.as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        outsider_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+    let whitelist_entry = WhitelistedRecipient {
+        treasury: treasury_pda,
+        recipient: recipient_pubkey,
+        label: "Auditor".to_string(),
+        added_by: authority_pubkey,
+        added_at: 0,
+    };
+    add_program_account(&mut program_test, whitelist_entry_pda, &whitelist_entry, 166);
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::remove_whitelist_recipient_ix_setup(
+        &outsider_keypair,
+        treasury_pda,
+        None,
+        whitelist_entry_pda,
+        &name,
+        recipient_pubkey,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
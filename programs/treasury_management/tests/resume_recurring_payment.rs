@@ -0,0 +1,451 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, get_program_account, process_transaction_with_events, recurring_payment_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, RecurringPayment, RecurringPaymentAction, RecurringPaymentChanged, RecurringSchedule};
+
+
+#[tokio::test]
+async fn resume_recurring_payment_ix_skips_installments_missed_while_paused() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.is_paused = true;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::resume_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let (result, events) =
+		process_transaction_with_events::<RecurringPaymentChanged>(&mut banks_client, ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let clock: Clock = banks_client.get_sysvar().await.unwrap();
+	let recurring_payment: RecurringPayment = get_program_account(&mut banks_client, recurring_payment_pda).await;
+	assert!(!recurring_payment.is_paused);
+	assert_eq!(recurring_payment.payments_made, 0);
+	// Back on the every-100-seconds schedule, at the first slot from now on.
+	assert_eq!(recurring_payment.next_payment_date % 100, 0);
+	assert!(recurring_payment.next_payment_date >= clock.unix_timestamp);
+	assert!(recurring_payment.next_payment_date < clock.unix_timestamp + 100);
+
+	assert_eq!(
+		events,
+		vec![RecurringPaymentChanged {
+			recurring_payment: recurring_payment_pda,
+			treasury: treasury_pda,
+			actor: authority_pubkey,
+			action: RecurringPaymentAction::Resumed,
+			old_amount: 1_000,
+			new_amount: 1_000,
+			old_destination: None,
+			new_destination: None,
+			old_next_payment_date: 0,
+			new_next_payment_date: recurring_payment.next_payment_date,
+		}],
+	);
+}
+
+#[tokio::test]
+async fn resume_recurring_payment_ix_rejects_payment_that_is_not_paused() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::resume_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::RecurringPaymentNotPaused);
+}
+
+#[tokio::test]
+async fn resume_recurring_payment_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.is_paused = true;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::resume_recurring_payment_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn resume_recurring_payment_ix_keeps_quarterly_cadence() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.is_paused = true;
+	// 2027-04-15
+	recurring_payment.schedule = RecurringSchedule::Quarterly { day: 15, month: 1 };
+	recurring_payment.next_payment_date = 1_807_747_200;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let recent_blockhash = context.last_blockhash;
+
+	// 2027-05-20
+	let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+	clock.unix_timestamp = 1_810_771_200;
+	context.set_sysvar(&clock);
+	let banks_client = &mut context.banks_client;
+
+	let ix = treasury_management_ix_interface::resume_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recurring_payment: RecurringPayment = get_program_account(banks_client, recurring_payment_pda).await;
+	assert!(!recurring_payment.is_paused);
+	// 2027-07-15, a quarter after 2027-04-15 rather than the next month.
+	assert_eq!(recurring_payment.next_payment_date, 1_815_609_600);
+}
+
+#[tokio::test]
+async fn resume_recurring_payment_ix_keeps_yearly_cadence() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Payroll Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (recurring_payment_pda, _recurring_payment_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"recurring",
+			treasury_pda.as_ref(),
+			recipient_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut recurring_payment = recurring_payment_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	recurring_payment.is_paused = true;
+	// 2027-03-01
+	recurring_payment.schedule = RecurringSchedule::Yearly { day: 1, month: 3 };
+	recurring_payment.next_payment_date = 1_803_859_200;
+	add_program_account(&mut program_test, recurring_payment_pda, &recurring_payment, 217);
+
+	// INSTRUCTIONS
+	let mut context = program_test.start_with_context().await;
+	let recent_blockhash = context.last_blockhash;
+
+	// 2027-04-10
+	let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+	clock.unix_timestamp = 1_807_315_200;
+	context.set_sysvar(&clock);
+	let banks_client = &mut context.banks_client;
+
+	let ix = treasury_management_ix_interface::resume_recurring_payment_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		None,
+		recurring_payment_pda,
+		&name,
+		recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let recurring_payment: RecurringPayment = get_program_account(banks_client, recurring_payment_pda).await;
+	assert!(!recurring_payment.is_paused);
+	// 2028-03-01, a year after 2027-03-01 rather than the next month.
+	assert_eq!(recurring_payment.next_payment_date, 1_835_481_600);
+}
@@ -0,0 +1,100 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, treasury_fixture};
+use treasury_management::VoteDelegation;
+
+
+
+#[tokio::test]
+async fn revoke_delegation_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let delegator_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let delegator_pubkey = delegator_keypair.pubkey();
+	let delegate_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (delegation_pda, _delegation_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_delegation",
+			treasury_pda.as_ref(),
+			delegator_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		delegator_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, delegator_pubkey];
+	treasury.signer_weights = vec![1, 1];
+	treasury.threshold = 2;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let delegation = VoteDelegation {
+		treasury: treasury_pda,
+		delegator: delegator_pubkey,
+		delegate: delegate_pubkey,
+		expires_at: None,
+	};
+	add_program_account(&mut program_test, delegation_pda, &delegation, 113);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::revoke_delegation_ix_setup(
+		&delegator_keypair,
+		treasury_pda,
+		delegation_pda,
+		&name,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let delegation = banks_client.get_account(delegation_pda).await.unwrap();
+	assert!(delegation.is_none());
+}
@@ -0,0 +1,121 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, get_program_account, proposal_fixture, treasury_fixture};
+use treasury_management::{Proposal, VoteRecord};
+
+
+
+#[tokio::test]
+async fn revoke_token_vote_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let id: u64 = 1;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"proposal",
+			treasury_pda.as_ref(),
+			id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (vote_record_pda, _vote_record_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"vote_record",
+			proposal_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+	proposal.governance_mint = Some(governance_mint_pubkey);
+	proposal.votes_for = 400;
+	add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+	let vote_record = VoteRecord {
+		proposal: proposal_pda,
+		voter: voter_pubkey,
+		vote_for: true,
+		weight: 400,
+		proposal_nonce: proposal.nonce,
+	};
+	add_program_account(&mut program_test, vote_record_pda, &vote_record, 89);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::revoke_token_vote_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		proposal_pda,
+		vote_record_pda,
+		&name,
+		id,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+	assert_eq!(proposal.votes_for, 0);
+
+	let vote_record = banks_client.get_account(vote_record_pda).await.unwrap();
+	assert!(vote_record.is_none());
+}
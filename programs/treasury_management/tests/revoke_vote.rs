@@ -0,0 +1,190 @@
+pub mod common;
+
+use std::str::FromStr;
+use {
+    common::{
+        get_program_test,
+        treasury_management_ix_interface,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, get_program_account, proposal_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Proposal, ProposalStatus, ProposalVote};
+
+
+
+#[tokio::test]
+async fn revoke_vote_ix_success() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name: String = "Test Treasury".to_string();
+    let id: u64 = 1;
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let voter_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let voter_pubkey = voter_keypair.pubkey();
+    let recipient_pubkey = Pubkey::new_unique();
+    let token_mint_pubkey = Pubkey::new_unique();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"proposal",
+            treasury_pda.as_ref(),
+            id.to_le_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        voter_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // 2-of-3 treasury where the voter is one of the signers.
+    let mut treasury = treasury_fixture(&name, authority_pubkey);
+    treasury.signers = vec![authority_pubkey, voter_pubkey, Pubkey::new_unique()];
+    treasury.signer_weights = vec![1, 1, 1];
+    treasury.threshold = 2;
+    add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+    let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+    proposal.votes_for = 1;
+    proposal.votes_against = 1;
+    proposal.votes = vec![
+        ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey },
+        ProposalVote { voter: voter_pubkey, vote_for: false, weight: 1, cast_by: voter_pubkey },
+    ];
+    add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::revoke_vote_ix_setup(
+        &voter_keypair,
+        treasury_pda,
+        proposal_pda,
+        &name,
+        id,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert!(result.is_ok());
+
+    let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+    assert_eq!(proposal.votes_for, 1);
+    assert_eq!(proposal.votes_against, 0);
+    assert_eq!(proposal.votes, vec![ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey }]);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+}
+
+#[tokio::test]
+async fn revoke_vote_ix_rejects_voter_without_vote() {
+    let mut program_test = get_program_test();
+
+    // PROGRAMS
+    program_test.prefer_bpf(true);
+
+    // DATA
+    let name: String = "Test Treasury".to_string();
+    let id: u64 = 1;
+
+    // KEYPAIR
+    let authority_keypair = Keypair::new();
+    let voter_keypair = Keypair::new();
+
+    // PUBKEY
+    let authority_pubkey = authority_keypair.pubkey();
+    let voter_pubkey = voter_keypair.pubkey();
+    let recipient_pubkey = Pubkey::new_unique();
+    let token_mint_pubkey = Pubkey::new_unique();
+
+    // PDA
+    let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            name.as_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"proposal",
+            treasury_pda.as_ref(),
+            id.to_le_bytes().as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    // ACCOUNT PROGRAM TEST SETUP
+    program_test.add_account(
+        voter_pubkey,
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // 2-of-3 treasury where the voter is one of the signers.
+    let mut treasury = treasury_fixture(&name, authority_pubkey);
+    treasury.signers = vec![authority_pubkey, voter_pubkey, Pubkey::new_unique()];
+    treasury.signer_weights = vec![1, 1, 1];
+    treasury.threshold = 2;
+    add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+    let mut proposal = proposal_fixture(treasury_pda, authority_pubkey, token_mint_pubkey, recipient_pubkey);
+    proposal.votes_for = 1;
+    proposal.votes = vec![ProposalVote { voter: authority_pubkey, vote_for: true, weight: 1, cast_by: authority_pubkey }];
+    add_program_account(&mut program_test, proposal_pda, &proposal, 1504);
+
+    // INSTRUCTIONS
+    let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+    let ix = treasury_management_ix_interface::revoke_vote_ix_setup(
+        &voter_keypair,
+        treasury_pda,
+        proposal_pda,
+        &name,
+        id,
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix).await;
+
+    // ASSERTIONS
+    assert_treasury_error(result, TreasuryManagementError::VoteNotFound);
+}
@@ -0,0 +1,624 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_program_account, assert_treasury_error, get_program_account, payment_stream_fixture, role_fixture, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, PaymentStream};
+
+
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		None,
+		None,
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payment_stream: PaymentStream = get_program_account(&mut banks_client, payment_stream_pda).await;
+	assert_eq!(payment_stream.recipient, new_recipient_pubkey);
+	assert_eq!(payment_stream.total_paid, 400);
+}
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_rejects_signer_other_than_recipient() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		None,
+		None,
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_requires_approval_when_configured() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	payment_stream.transfer_requires_approval = true;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		None,
+		None,
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::StreamTransferNotApproved);
+}
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_accepts_treasury_approval() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	payment_stream.transfer_requires_approval = true;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		Some(&authority_keypair),
+		None,
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payment_stream: PaymentStream = get_program_account(&mut banks_client, payment_stream_pda).await;
+	assert_eq!(payment_stream.recipient, new_recipient_pubkey);
+	assert_eq!(payment_stream.total_paid, 400);
+}
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_transfer_stream_recipient_ix_accepts_role_holder_approval() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (approver_role_pda, _approver_role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			outsider_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	payment_stream.transfer_requires_approval = true;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+	add_program_account(&mut program_test, approver_role_pda, &role_fixture(treasury_pda, outsider_pubkey, 1), 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		Some(&outsider_keypair),
+		Some(approver_role_pda),
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let payment_stream: PaymentStream = get_program_account(&mut banks_client, payment_stream_pda).await;
+	assert_eq!(payment_stream.recipient, new_recipient_pubkey);
+}
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_transfer_stream_recipient_ix_rejects_role_of_another_user() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (approver_role_pda, _approver_role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			authority_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	payment_stream.transfer_requires_approval = true;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+	// A role that belongs to someone other than the approver.
+	add_program_account(&mut program_test, approver_role_pda, &role_fixture(treasury_pda, authority_pubkey, 1), 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		Some(&outsider_keypair),
+		Some(approver_role_pda),
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_err());
+
+	let payment_stream: PaymentStream = get_program_account(&mut banks_client, payment_stream_pda).await;
+	assert_eq!(payment_stream.recipient, recipient_pubkey);
+}
+
+#[tokio::test]
+async fn transfer_stream_recipient_ix_rejects_approver_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Stream Treasury".to_string();
+	let stream_id: u64 = 0;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let recipient_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let recipient_pubkey = recipient_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let new_recipient_pubkey = Pubkey::new_unique();
+	let token_mint_pubkey = Pubkey::new_unique();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (payment_stream_pda, _payment_stream_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"payment_stream",
+			treasury_pda.as_ref(),
+			stream_id.to_le_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	for payer in [authority_pubkey, recipient_pubkey, outsider_pubkey] {
+		program_test.add_account(
+			payer,
+			Account {
+				lamports: 1_000_000_000_000,
+				data: vec![],
+				owner: system_program::ID,
+				executable: false,
+				rent_epoch: 0,
+			},
+		);
+	}
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+	let mut payment_stream = payment_stream_fixture(treasury_pda, recipient_pubkey, token_mint_pubkey);
+	payment_stream.total_paid = 400;
+	payment_stream.transfer_requires_approval = true;
+	add_program_account(&mut program_test, payment_stream_pda, &payment_stream, 236);
+
+	// INSTRUCTIONS
+	let (banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::transfer_stream_recipient_ix_setup(
+		&recipient_keypair,
+		treasury_pda,
+		payment_stream_pda,
+		Some(&outsider_keypair),
+		None,
+		&name,
+		stream_id,
+		new_recipient_pubkey,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
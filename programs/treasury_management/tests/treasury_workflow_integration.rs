@@ -13,6 +13,9 @@ use {
     },
 };
 
+use common::get_program_account;
+use treasury_management::{Proposal, ProposalStatus};
+
 
 #[tokio::test]
 async fn treasury_workflow_integration_test() {
@@ -57,6 +60,7 @@ async fn treasury_workflow_integration_test() {
 
     // Create signers array
     let signers = vec![admin_pubkey, signer1_pubkey, signer2_pubkey];
+    let signer_weights: Vec<u8> = vec![1, 1, 1];
 
     // EXECUTABLE PUBKEY
     let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
@@ -81,6 +85,33 @@ async fn treasury_workflow_integration_test() {
     );
 
     let proposal_id: u64 = 1;
+    let (treasurer_role_pda, _treasurer_role_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"role",
+            treasury_pda.as_ref(),
+            treasurer_pubkey.as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (signer1_role_pda, _signer1_role_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"role",
+            treasury_pda.as_ref(),
+            signer1_pubkey.as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
+    let (contributor_role_pda, _contributor_role_pda_bump) = Pubkey::find_program_address(
+        &[
+            b"role",
+            treasury_pda.as_ref(),
+            contributor_pubkey.as_ref(),
+        ],
+        &treasury_management::ID,
+    );
+
     let (proposal_pda, _proposal_pda_bump) = Pubkey::find_program_address(
         &[
             b"proposal",
@@ -156,6 +187,7 @@ async fn treasury_workflow_integration_test() {
         system_program_pubkey,
         &name,
         &signers,
+        &signer_weights,
         threshold,
         admin_limit,
         treasurer_limit,
@@ -173,9 +205,12 @@ async fn treasury_workflow_integration_test() {
     let ix_assign_treasurer = treasury_management_ix_interface::assign_role_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
+        treasurer_role_pda,
+        system_program_pubkey,
         &name,
         treasurer_pubkey,
-        1, // Treasurer role
+        2, // Treasurer role
         true, // can_execute_payments
         true, // can_create_streams
         false, // can_manage_roles
@@ -192,9 +227,12 @@ async fn treasury_workflow_integration_test() {
     let ix_assign_contributor = treasury_management_ix_interface::assign_role_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
+        contributor_role_pda,
+        system_program_pubkey,
         &name,
         contributor_pubkey,
-        2, // Contributor role
+        3, // Contributor role
         false, // can_execute_payments
         false, // can_create_streams
         false, // can_manage_roles
@@ -207,10 +245,33 @@ async fn treasury_workflow_integration_test() {
     let result = banks_client.process_transaction(ix_assign_contributor).await;
     assert!(result.is_ok(), "Failed to assign contributor role: {:?}", result);
 
+    // Signer 1 only needs to be able to vote
+    let ix_assign_signer1 = treasury_management_ix_interface::assign_role_ix_setup(
+        &admin_keypair,
+        treasury_pda,
+        None,
+        signer1_role_pda,
+        system_program_pubkey,
+        &name,
+        signer1_pubkey,
+        3, // Contributor role
+        false, // can_execute_payments
+        false, // can_create_streams
+        false, // can_manage_roles
+        true, // can_view_treasury
+        false, // can_propose
+        true, // can_vote
+        recent_blockhash,
+    );
+
+    let result = banks_client.process_transaction(ix_assign_signer1).await;
+    assert!(result.is_ok(), "Failed to assign signer 1 role: {:?}", result);
+
     // Step 4: Add Whitelist Recipient
     let ix_add_whitelist = treasury_management_ix_interface::add_whitelist_recipient_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
         whitelist_entry_pda,
         system_program_pubkey,
         &name,
@@ -226,6 +287,7 @@ async fn treasury_workflow_integration_test() {
     let ix_create_proposal = treasury_management_ix_interface::create_proposal_ix_setup(
         &contributor_keypair,
         treasury_pda,
+        Some(contributor_role_pda),
         proposal_pda,
         system_program_pubkey,
         &name,
@@ -245,7 +307,9 @@ async fn treasury_workflow_integration_test() {
     let ix_vote_admin = treasury_management_ix_interface::vote_on_proposal_ix_setup(
         &admin_keypair,
         treasury_pda,
+        None,
         proposal_pda,
+        None,
         &name,
         proposal_id,
         true, // vote for
@@ -255,19 +319,24 @@ async fn treasury_workflow_integration_test() {
     let result = banks_client.process_transaction(ix_vote_admin).await;
     assert!(result.is_ok(), "Failed to vote on proposal (admin): {:?}", result);
 
-    // Step 7: Vote on Proposal (Treasurer)
-    let ix_vote_treasurer = treasury_management_ix_interface::vote_on_proposal_ix_setup(
-        &treasurer_keypair,
+    // Step 7: Vote on Proposal (Signer 1), reaching the 2-of-3 threshold
+    let ix_vote_signer1 = treasury_management_ix_interface::vote_on_proposal_ix_setup(
+        &signer1_keypair,
         treasury_pda,
+        Some(signer1_role_pda),
         proposal_pda,
+        None,
         &name,
         proposal_id,
         true, // vote for
         recent_blockhash,
     );
 
-    let result = banks_client.process_transaction(ix_vote_treasurer).await;
-    assert!(result.is_ok(), "Failed to vote on proposal (treasurer): {:?}", result);
+    let result = banks_client.process_transaction(ix_vote_signer1).await;
+    assert!(result.is_ok(), "Failed to vote on proposal (signer 1): {:?}", result);
+
+    let proposal: Proposal = get_program_account(&mut banks_client, proposal_pda).await;
+    assert_eq!(proposal.status, ProposalStatus::Approved);
 
     // Note: In a real integration test, we would also test:
     // - Execute proposal (requires token accounts setup)
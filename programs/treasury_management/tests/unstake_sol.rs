@@ -13,6 +13,10 @@ use {
     },
 };
 
+use common::{add_program_account, assert_treasury_error, treasury_fixture};
+use treasury_management::error::TreasuryManagementError;
+
+
 
 #[tokio::test]
 async fn unstake_sol_ix_success() {
@@ -70,6 +74,7 @@ async fn unstake_sol_ix_success() {
 	let ix = treasury_management_ix_interface::unstake_sol_ix_setup(
 		&authority_keypair,
 		treasury_pda,
+		None,
 		&name,
 		amount,
 		recent_blockhash,
@@ -81,3 +86,62 @@ async fn unstake_sol_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn unstake_sol_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::unstake_sol_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		&name,
+		1_000,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
@@ -13,6 +13,10 @@ use {
     },
 };
 
+use common::{add_program_account, assert_treasury_error, get_program_account, treasury_fixture};
+use treasury_management::{error::TreasuryManagementError, Treasury};
+
+
 
 #[tokio::test]
 async fn update_treasury_config_ix_success() {
@@ -21,34 +25,193 @@ async fn update_treasury_config_ix_success() {
 	// PROGRAMS
 	program_test.prefer_bpf(true);
 
-	program_test.add_program(
-		"account_compression",
-		Pubkey::from_str("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK").unwrap(),
+	// DATA
+	let name = "Test Treasury".to_string();
+	let new_threshold: u8 = 2;
+	let admin_limit: u64 = 2_000_000_000;
+	let treasurer_limit: u64 = 1_000_000_000;
+	let contributor_limit: u64 = 200_000_000;
+	let reset_period: u64 = 86400;
+	let auto_stake: bool = true;
+	let stake_target_percentage: u8 = 25;
+	let voting_period: i64 = 86400;
+	let execution_grace_period: i64 = 172800;
+	let execution_delay: i64 = 3600;
+	let execution_delay_amount_step: u64 = 1_000_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let new_signers: Vec<Pubkey> = vec![authority_pubkey, Pubkey::new_unique(), Pubkey::new_unique()];
+	let new_signer_weights: Vec<u8> = vec![1, 1, 1];
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::update_treasury_config_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		&name,
+		&new_signers,
+		&new_signer_weights,
+		new_threshold,
+		admin_limit,
+		treasurer_limit,
+		contributor_limit,
+		reset_period,
+		auto_stake,
+		stake_target_percentage,
+		voting_period,
+		execution_grace_period,
+		execution_delay,
+		execution_delay_amount_step,
 		None,
+		10,
+		50,
+		recent_blockhash,
 	);
 
-	program_test.add_program(
-		"noop",
-		Pubkey::from_str("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV").unwrap(),
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let treasury: Treasury = get_program_account(&mut banks_client, treasury_pda).await;
+	assert_eq!(treasury.signers, new_signers);
+	assert_eq!(treasury.threshold, new_threshold);
+	assert_eq!(treasury.treasurer_limit, treasurer_limit);
+	assert_eq!(treasury.voting_period, voting_period);
+	assert_eq!(treasury.execution_grace_period, execution_grace_period);
+	assert_eq!(treasury.execution_delay, execution_delay);
+	assert_eq!(treasury.execution_delay_amount_step, execution_delay_amount_step);
+}
+
+#[tokio::test]
+async fn update_treasury_config_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::update_treasury_config_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		&name,
+		&[outsider_pubkey],
+		&[1],
+		1,
+		0,
+		0,
+		0,
+		0,
+		false,
+		0,
+		259_200,
+		604_800,
+		86_400,
+		0,
 		None,
+		10,
+		50,
+		recent_blockhash,
 	);
 
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn update_treasury_config_ix_rejects_zero_voting_period() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
 	// DATA
-	let name: String = Default::default();
-	let new_signers: Vec<Pubkey> = vec![Pubkey::default()];
-	let new_threshold: u8 = Default::default();
-	let admin_limit: u64 = Default::default();
-	let treasurer_limit: u64 = Default::default();
-	let contributor_limit: u64 = Default::default();
-	let reset_period: u64 = Default::default();
-	let auto_stake: bool = Default::default();
-	let stake_target_percentage: u8 = Default::default();
+	let name = "Test Treasury".to_string();
+	let new_threshold: u8 = 2;
+	let admin_limit: u64 = 2_000_000_000;
+	let treasurer_limit: u64 = 1_000_000_000;
+	let contributor_limit: u64 = 200_000_000;
+	let reset_period: u64 = 86400;
+	let auto_stake: bool = true;
+	let stake_target_percentage: u8 = 25;
+	let voting_period: i64 = 0;
+	let execution_grace_period: i64 = 172800;
+	let execution_delay: i64 = 3600;
+	let execution_delay_amount_step: u64 = 1_000_000_000;
 
 	// KEYPAIR
 	let authority_keypair = Keypair::new();
 
 	// PUBKEY
 	let authority_pubkey = authority_keypair.pubkey();
+	let new_signers: Vec<Pubkey> = vec![authority_pubkey, Pubkey::new_unique(), Pubkey::new_unique()];
+	let new_signer_weights: Vec<u8> = vec![1, 1, 1];
 
 	// PDA
 	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
@@ -71,6 +234,8 @@ async fn update_treasury_config_ix_success() {
 		},
 	);
 
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
 	// INSTRUCTIONS
 	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
 
@@ -78,7 +243,8 @@ async fn update_treasury_config_ix_success() {
 		&authority_keypair,
 		treasury_pda,
 		&name,
-		new_signers,
+		&new_signers,
+		&new_signer_weights,
 		new_threshold,
 		admin_limit,
 		treasurer_limit,
@@ -86,12 +252,194 @@ async fn update_treasury_config_ix_success() {
 		reset_period,
 		auto_stake,
 		stake_target_percentage,
+		voting_period,
+		execution_grace_period,
+		execution_delay,
+		execution_delay_amount_step,
+		None,
+		10,
+		50,
 		recent_blockhash,
 	);
 
 	let result = banks_client.process_transaction(ix).await;
 
 	// ASSERTIONS
-	assert!(result.is_ok());
+	assert_treasury_error(result, TreasuryManagementError::InvalidGovernancePeriod);
+}
+
+#[tokio::test]
+async fn update_treasury_config_ix_rejects_governance_mint_change_while_escrowed() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
 
+	// DATA
+	let name = "Test Treasury".to_string();
+	let new_threshold: u8 = 1;
+	let admin_limit: u64 = 2_000_000_000;
+	let treasurer_limit: u64 = 1_000_000_000;
+	let contributor_limit: u64 = 200_000_000;
+	let reset_period: u64 = 86400;
+	let auto_stake: bool = true;
+	let stake_target_percentage: u8 = 25;
+	let voting_period: i64 = 86400;
+	let execution_grace_period: i64 = 172800;
+	let execution_delay: i64 = 3600;
+	let execution_delay_amount_step: u64 = 1_000_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let new_signers: Vec<Pubkey> = vec![authority_pubkey];
+	let new_signer_weights: Vec<u8> = vec![1];
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// Holders still have tokens escrowed under the current governance mint.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(Pubkey::new_unique());
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::update_treasury_config_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		&name,
+		&new_signers,
+		&new_signer_weights,
+		new_threshold,
+		admin_limit,
+		treasurer_limit,
+		contributor_limit,
+		reset_period,
+		auto_stake,
+		stake_target_percentage,
+		voting_period,
+		execution_grace_period,
+		execution_delay,
+		execution_delay_amount_step,
+		Some(Pubkey::new_unique()),
+		10,
+		50,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::GovernanceTokensEscrowed);
+}
+
+#[tokio::test]
+async fn update_treasury_config_ix_rejects_multisig_treasury() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Test Treasury".to_string();
+	let new_threshold: u8 = 2;
+	let admin_limit: u64 = 2_000_000_000;
+	let treasurer_limit: u64 = 1_000_000_000;
+	let contributor_limit: u64 = 200_000_000;
+	let reset_period: u64 = 86400;
+	let auto_stake: bool = true;
+	let stake_target_percentage: u8 = 25;
+	let voting_period: i64 = 86400;
+	let execution_grace_period: i64 = 172800;
+	let execution_delay: i64 = 3600;
+	let execution_delay_amount_step: u64 = 1_000_000_000;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let new_signers: Vec<Pubkey> = vec![authority_pubkey, Pubkey::new_unique(), Pubkey::new_unique()];
+	let new_signer_weights: Vec<u8> = vec![1, 1, 1];
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		authority_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	// Once there are several signers, changes must go through a proposal.
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.signers = vec![authority_pubkey, Pubkey::new_unique()];
+	treasury.signer_weights = vec![1, 1];
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::update_treasury_config_ix_setup(
+		&authority_keypair,
+		treasury_pda,
+		&name,
+		&new_signers,
+		&new_signer_weights,
+		new_threshold,
+		admin_limit,
+		treasurer_limit,
+		contributor_limit,
+		reset_period,
+		auto_stake,
+		stake_target_percentage,
+		voting_period,
+		execution_grace_period,
+		execution_delay,
+		execution_delay_amount_step,
+		None,
+		10,
+		50,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::ConfigChangeRequiresProposal);
 }
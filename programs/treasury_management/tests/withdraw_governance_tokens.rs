@@ -0,0 +1,237 @@
+pub mod common;
+
+use {
+    common::{
+		get_program_test,
+		treasury_management_ix_interface,
+	},
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program,
+    },
+};
+
+use common::{add_mint_account, add_program_account, add_token_account, assert_treasury_error, get_program_account, treasury_fixture, voter_escrow_fixture};
+use treasury_management::{error::TreasuryManagementError, Treasury, VoterEscrow};
+use anchor_spl::token::TokenAccount;
+
+
+
+#[tokio::test]
+async fn withdraw_governance_tokens_ix_success() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let amount: u64 = 150;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			governance_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+	add_program_account(&mut program_test, voter_escrow_pda, &voter_escrow_fixture(treasury_pda, voter_pubkey, 400), 88);
+	add_mint_account(&mut program_test, governance_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, escrow_vault_pda, governance_mint_pubkey, escrow_authority_pda, 400);
+	add_token_account(&mut program_test, destination_pubkey, governance_mint_pubkey, voter_pubkey, 0);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::withdraw_governance_tokens_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		governance_mint_pubkey,
+		destination_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		voter_escrow_pda,
+		token_program_pubkey,
+		&name,
+		amount,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+
+	let voter_escrow: VoterEscrow = get_program_account(&mut banks_client, voter_escrow_pda).await;
+	assert_eq!(voter_escrow.amount, 250);
+
+	let destination: TokenAccount = get_program_account(&mut banks_client, destination_pubkey).await;
+	assert_eq!(destination.amount, amount);
+
+	let treasury: Treasury = get_program_account(&mut banks_client, treasury_pda).await;
+	assert_eq!(treasury.governance_deposits, 250);
+}
+
+#[tokio::test]
+async fn withdraw_governance_tokens_ix_rejects_while_votes_are_active() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Token Governed Treasury".to_string();
+	let amount: u64 = 150;
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let voter_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let voter_pubkey = voter_keypair.pubkey();
+	let governance_mint_pubkey = Pubkey::new_unique();
+	let destination_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let token_program_pubkey = anchor_spl::token::ID;
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_authority_pda, _escrow_authority_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"escrow_authority",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (escrow_vault_pda, _escrow_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"governance_escrow",
+			treasury_pda.as_ref(),
+			governance_mint_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (voter_escrow_pda, _voter_escrow_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"voter_escrow",
+			treasury_pda.as_ref(),
+			voter_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		voter_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	let mut treasury = treasury_fixture(&name, authority_pubkey);
+	treasury.governance_mint = Some(governance_mint_pubkey);
+	treasury.governance_deposits = 400;
+	add_program_account(&mut program_test, treasury_pda, &treasury, 566);
+
+	// The voter has voted on a proposal whose voting period has not ended.
+	let mut voter_escrow = voter_escrow_fixture(treasury_pda, voter_pubkey, 400);
+	voter_escrow.locked_until = i64::MAX;
+	add_program_account(&mut program_test, voter_escrow_pda, &voter_escrow, 88);
+	add_mint_account(&mut program_test, governance_mint_pubkey, authority_pubkey, 6);
+	add_token_account(&mut program_test, escrow_vault_pda, governance_mint_pubkey, escrow_authority_pda, 400);
+	add_token_account(&mut program_test, destination_pubkey, governance_mint_pubkey, voter_pubkey, 0);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::withdraw_governance_tokens_ix_setup(
+		&voter_keypair,
+		treasury_pda,
+		governance_mint_pubkey,
+		destination_pubkey,
+		escrow_authority_pda,
+		escrow_vault_pda,
+		voter_escrow_pda,
+		token_program_pubkey,
+		&name,
+		amount,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::GovernanceTokensLocked);
+}
@@ -13,6 +13,10 @@ use {
     },
 };
 
+use common::{add_program_account, assert_treasury_error, role_fixture, treasury_fixture};
+use treasury_management::error::TreasuryManagementError;
+
+
 
 #[tokio::test]
 async fn withdraw_sol_ix_success() {
@@ -44,6 +48,9 @@ async fn withdraw_sol_ix_success() {
 	let authority_pubkey = authority_keypair.pubkey();
 	let recipient_pubkey = Pubkey::new_unique();
 
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
 	// PDA
 	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
 		&[
@@ -53,6 +60,14 @@ async fn withdraw_sol_ix_success() {
 		&treasury_management::ID,
 	);
 
+	let (sol_vault_pda, _sol_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"sol_vault",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
 	// ACCOUNT PROGRAM TEST SETUP
 	program_test.add_account(
 		authority_pubkey,
@@ -71,7 +86,10 @@ async fn withdraw_sol_ix_success() {
 	let ix = treasury_management_ix_interface::withdraw_sol_ix_setup(
 		&authority_keypair,
 		treasury_pda,
+		None,
+		sol_vault_pda,
 		recipient_pubkey,
+		system_program_pubkey,
 		&name,
 		amount,
 		recent_blockhash,
@@ -83,3 +101,276 @@ async fn withdraw_sol_ix_success() {
 	assert!(result.is_ok());
 
 }
+
+#[tokio::test]
+async fn withdraw_sol_ix_rejects_signer_without_permission() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Permissioned Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let outsider_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let outsider_pubkey = outsider_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (sol_vault_pda, _sol_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"sol_vault",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		outsider_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::withdraw_sol_ix_setup(
+		&outsider_keypair,
+		treasury_pda,
+		None,
+		sol_vault_pda,
+		recipient_pubkey,
+		system_program_pubkey,
+		&name,
+		1_000,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::InsufficientPermissions);
+}
+
+#[tokio::test]
+async fn withdraw_sol_ix_rejects_amount_over_spending_limit() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Limited Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (treasurer_role_pda, _treasurer_role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			treasurer_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (sol_vault_pda, _sol_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"sol_vault",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		treasurer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		sol_vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// The fixture treasury allows treasurers 500_000_000 per week.
+	let treasurer_role = role_fixture(treasury_pda, treasurer_pubkey, 2);
+	add_program_account(&mut program_test, treasurer_role_pda, &treasurer_role, 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::withdraw_sol_ix_setup(
+		&treasurer_keypair,
+		treasury_pda,
+		Some(treasurer_role_pda),
+		sol_vault_pda,
+		recipient_pubkey,
+		system_program_pubkey,
+		&name,
+		600_000_000,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert_treasury_error(result, TreasuryManagementError::SpendingLimitExceeded);
+}
+
+
+#[tokio::test]
+async fn withdraw_sol_ix_resets_spending_limit_after_period() {
+	let mut program_test = get_program_test();
+
+	// PROGRAMS
+	program_test.prefer_bpf(true);
+
+	// DATA
+	let name = "Limited Treasury".to_string();
+
+	// KEYPAIR
+	let authority_keypair = Keypair::new();
+	let treasurer_keypair = Keypair::new();
+
+	// PUBKEY
+	let authority_pubkey = authority_keypair.pubkey();
+	let treasurer_pubkey = treasurer_keypair.pubkey();
+	let recipient_pubkey = Pubkey::new_unique();
+
+	// EXECUTABLE PUBKEY
+	let system_program_pubkey = Pubkey::from_str("11111111111111111111111111111111").unwrap();
+
+	// PDA
+	let (treasury_pda, _treasury_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"treasury",
+			name.as_bytes().as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (treasurer_role_pda, _treasurer_role_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"role",
+			treasury_pda.as_ref(),
+			treasurer_pubkey.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	let (sol_vault_pda, _sol_vault_pda_bump) = Pubkey::find_program_address(
+		&[
+			b"sol_vault",
+			treasury_pda.as_ref(),
+		],
+		&treasury_management::ID,
+	);
+
+	// ACCOUNT PROGRAM TEST SETUP
+	program_test.add_account(
+		treasurer_pubkey,
+		Account {
+			lamports: 1_000_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	program_test.add_account(
+		sol_vault_pda,
+		Account {
+			lamports: 10_000_000_000,
+			data: vec![],
+			owner: system_program::ID,
+			executable: false,
+			rent_epoch: 0,
+		},
+	);
+
+	add_program_account(&mut program_test, treasury_pda, &treasury_fixture(&name, authority_pubkey), 566);
+
+	// The fixture treasury allows treasurers 500_000_000 per week.
+	// The limit was used up at the last reset, which is long past.
+	let mut treasurer_role = role_fixture(treasury_pda, treasurer_pubkey, 2);
+	treasurer_role.spending_limit_used = 500_000_000;
+	add_program_account(&mut program_test, treasurer_role_pda, &treasurer_role, 95);
+
+	// INSTRUCTIONS
+	let (mut banks_client, _, recent_blockhash) = program_test.start().await;
+
+	let ix = treasury_management_ix_interface::withdraw_sol_ix_setup(
+		&treasurer_keypair,
+		treasury_pda,
+		Some(treasurer_role_pda),
+		sol_vault_pda,
+		recipient_pubkey,
+		system_program_pubkey,
+		&name,
+		100_000_000,
+		recent_blockhash,
+	);
+
+	let result = banks_client.process_transaction(ix).await;
+
+	// ASSERTIONS
+	assert!(result.is_ok());
+}